@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::{Answer, Mode, Part, Puzzle};
+
+/// Answers of the puzzle inputs, at the root of the repository.
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+pub const EXAMPLE_ANSWERS_FILE: &str = "src/examples/answers.toml";
+
+/// Expected answers, read from a file with one table per puzzle:
+///
+/// ```toml
+/// [2024.day17]
+/// part1 = "5,7,3,0"
+/// part2 = 117440
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    puzzles: HashMap<Puzzle, DayAnswers>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Number(u64),
+    Text(String),
+}
+
+impl From<&Expected> for Answer {
+    fn from(expected: &Expected) -> Self {
+        match expected {
+            Expected::Number(n) => Answer::Number((*n).into()),
+            Expected::Text(s) => Answer::Text(s.clone()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { path: PathBuf, error: io::Error },
+    Invalid { path: PathBuf, message: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io { path, error } => {
+                write!(f, "cannot read {}: {}", path.display(), error)
+            }
+            AnswersError::Invalid { path, message } => {
+                write!(f, "invalid answers file {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnswersError::Io { error, .. } => Some(error),
+            AnswersError::Invalid { .. } => None,
+        }
+    }
+}
+
+/// The answers file used when none is given explicitly.
+pub fn default_path(mode: Mode) -> &'static Path {
+    match mode {
+        Mode::Example => Path::new(EXAMPLE_ANSWERS_FILE),
+        Mode::Real => Path::new(DEFAULT_ANSWERS_FILE),
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Answers, AnswersError> {
+        let content = std::fs::read_to_string(path).map_err(|error| AnswersError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        Answers::parse(&content).map_err(|message| AnswersError::Invalid {
+            path: path.to_path_buf(),
+            message,
+        })
+    }
+
+    pub fn parse(content: &str) -> Result<Answers, String> {
+        let years: HashMap<String, toml::Table> =
+            toml::from_str(content).map_err(|e| e.message().to_string())?;
+
+        let mut puzzles = HashMap::new();
+        for (year_name, days) in years {
+            let year = year_name
+                .parse::<u16>()
+                .ok()
+                .filter(|year| *year >= crate::FIRST_YEAR)
+                .ok_or_else(|| {
+                    format!(
+                        "`[{}]` is not a year, expected tables such as `[2024.day1]`",
+                        year_name
+                    )
+                })?;
+            let days: HashMap<String, DayAnswers> = toml::Value::Table(days)
+                .try_into()
+                .map_err(|e: toml::de::Error| format!("[{}]: {}", year, e.message()))?;
+            for (name, answers) in days {
+                let day = name
+                    .strip_prefix("day")
+                    .and_then(|day| day.parse::<u8>().ok())
+                    .filter(|day| crate::DAYS.contains(day))
+                    .ok_or_else(|| {
+                        format!(
+                            "`[{}.{}]` is not a day, expected `[{}.day1]` to `[{}.day25]`",
+                            year, name, year, year
+                        )
+                    })?;
+                puzzles.insert(Puzzle::new(year, day), answers);
+            }
+        }
+
+        Ok(Answers { puzzles })
+    }
+
+    pub fn expected(&self, puzzle: Puzzle, part: Part) -> Option<Answer> {
+        let answers = self.puzzles.get(&puzzle)?;
+        match part {
+            Part::One => answers.part1.as_ref(),
+            Part::Two => answers.part2.as_ref(),
+        }
+        .map(Answer::from)
+    }
+
+    /// Compares a computed answer with the stored one.
+    pub fn check(&self, puzzle: Puzzle, part: Part, answer: &Answer) -> Verdict {
+        match self.expected(puzzle, part) {
+            Some(expected) if expected == *answer => Verdict::Match,
+            Some(expected) => Verdict::Mismatch(expected),
+            None => Verdict::Missing,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    /// The stored answer differs, holding the stored one
+    Mismatch(Answer),
+    /// No answer is stored yet for this part
+    Missing,
+}
@@ -0,0 +1,88 @@
+use std::path::Path;
+use std::time::Duration;
+
+use crate::runner::{self, timed, RunError};
+use crate::{Params, Part, Puzzle};
+
+/// Spread of the wall time of one step over repeated runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// `None` when there is no sample. The median of an even number of samples is the mean
+    /// of the two middle ones.
+    pub fn new(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (*sorted.get(middle.checked_sub(1)?)? + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        Some(Stats {
+            min: *sorted.first()?,
+            median,
+            max: *sorted.last()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayBench {
+    pub puzzle: Puzzle,
+    pub runs: usize,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+impl DayBench {
+    /// Median time of a whole run of the day: parsing and every benchmarked part.
+    pub fn median_total(&self) -> Duration {
+        self.parse.median + self.parts.iter().map(|(_, s)| s.median).sum::<Duration>()
+    }
+}
+
+/// Parses and solves `puzzle` `runs` times, reading its input only once.
+pub fn bench_day(
+    puzzle: Puzzle,
+    part: Option<Part>,
+    params: &Params,
+    input: Option<&Path>,
+    runs: usize,
+) -> Result<DayBench, RunError> {
+    assert!(runs > 0, "a benchmark needs at least one run");
+    let (solver, content) = runner::load(puzzle, params, input)?;
+    let parts: Vec<Part> = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|p| p.is_selected(part))
+        .collect();
+
+    let mut parse_samples = Vec::with_capacity(runs);
+    let mut part_samples = vec![Vec::with_capacity(runs); parts.len()];
+    for _ in 0..runs {
+        let (input, duration) = timed(|| solver.parse(&content));
+        let input = input?;
+        parse_samples.push(duration);
+
+        for (p, samples) in parts.iter().zip(part_samples.iter_mut()) {
+            samples.push(timed(|| solver.solve(input.as_ref(), *p)).1);
+        }
+    }
+
+    let stats = |samples: &[Duration]| Stats::new(samples).expect("at least one run");
+    Ok(DayBench {
+        puzzle,
+        runs,
+        parse: stats(&parse_samples),
+        parts: parts
+            .into_iter()
+            .zip(part_samples.iter().map(|s| stats(s)))
+            .collect(),
+    })
+}
@@ -0,0 +1,455 @@
+//! Downloads of puzzle inputs and submissions of answers, to the Advent of Code site or
+//! any server answering the same way. Inputs are cached in the input directory, requests
+//! are spaced out, and every answer sent is remembered along with the site's feedback so
+//! that the same guess is never sent twice.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::input::{self, input_path};
+use crate::{Answer, Part, Puzzle};
+
+/// Environment variable holding the session token, the value of the `session` cookie of
+/// a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding [`DEFAULT_BASE_URL`], e.g. for a mirror or a test
+/// server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Shortest time between two requests of the same client.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Answers sent for the puzzles of a year, next to their inputs.
+pub const SUBMISSIONS_FILE: &str = "submissions.json";
+
+/// Time of the last request to the site, in milliseconds since the Unix epoch, at the root
+/// of the cache directory so that requests stay spaced out across runs and years.
+pub const LAST_REQUEST_FILE: &str = "last-request";
+
+const USER_AGENT: &str = "advent-of-code harness";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientConfig {
+    /// Site root, without a trailing slash
+    pub base_url: String,
+    pub session: String,
+    /// Directory with one directory of inputs and submissions per year, as
+    /// [`input::input_dir`]
+    pub cache_dir: PathBuf,
+    pub min_interval: Duration,
+}
+
+impl ClientConfig {
+    /// The site at `base_url`, with or without a trailing slash, and the input directory the
+    /// runner reads.
+    pub fn new(base_url: &str, session: &str) -> Self {
+        ClientConfig {
+            base_url: base_url.trim().trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            cache_dir: input::input_dir(),
+            min_interval: DEFAULT_MIN_INTERVAL,
+        }
+    }
+
+    /// The token in [`SESSION_VAR`], the site in [`BASE_URL_VAR`] or the real one, and the
+    /// input directory the runner reads.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(ClientError::NoSession)?;
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(ClientConfig::new(&base_url, &session))
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session token in [`SESSION_VAR`]
+    NoSession,
+    /// The site refused the request, e.g. with an expired token or a locked day
+    Status {
+        url: String,
+        status: u16,
+    },
+    Http {
+        url: String,
+        error: ureq::Error,
+    },
+    /// The site answered a submission with a page the client does not know
+    UnexpectedReply {
+        url: String,
+    },
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    InvalidSubmissions {
+        path: PathBuf,
+        message: String,
+    },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(
+                f,
+                "no session token, set {} to the `session` cookie of the site",
+                SESSION_VAR
+            ),
+            ClientError::Status { url, status } => match status {
+                400 | 401 | 403 => write!(
+                    f,
+                    "{} refused the session token with status {}, is {} still valid?",
+                    url, status, SESSION_VAR
+                ),
+                404 => write!(f, "{} not found, is the day unlocked yet?", url),
+                _ => write!(f, "{} answered with status {}", url, status),
+            },
+            ClientError::Http { url, error } => write!(f, "cannot reach {}: {}", url, error),
+            ClientError::UnexpectedReply { url } => {
+                write!(f, "cannot make out the reply of {}", url)
+            }
+            ClientError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            ClientError::InvalidSubmissions { path, message } => {
+                write!(
+                    f,
+                    "invalid submissions file {}: {}",
+                    path.display(),
+                    message
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Http { error, .. } => Some(error),
+            ClientError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Where an input came from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// What the site said of an answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Feedback {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feedback::Correct => write!(f, "the right answer"),
+            Feedback::Wrong => write!(f, "not the right answer"),
+            Feedback::TooHigh => write!(f, "too high"),
+            Feedback::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Submission {
+    /// Sent, with the feedback of the site
+    Sent(Feedback),
+    /// Not sent: the answer got this feedback before, or it follows from earlier ones,
+    /// such as a number above one that was too high
+    Known(Feedback),
+    /// Not sent: the site asks to wait this long after a wrong answer
+    TooSoon(Duration),
+    /// The site does not expect an answer to this part, solved already or locked
+    WrongLevel,
+}
+
+/// An answer sent, as stored in [`SUBMISSIONS_FILE`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Sent {
+    day: u8,
+    part: u8,
+    answer: String,
+    feedback: Feedback,
+}
+
+impl Sent {
+    /// The feedback this earlier answer implies for another `answer`, if any.
+    fn implies(&self, answer: &str) -> Option<Feedback> {
+        let bounds = self
+            .answer
+            .parse::<u128>()
+            .ok()
+            .zip(answer.parse::<u128>().ok());
+        match (self.feedback, bounds) {
+            // Only one answer is right
+            (Feedback::Correct, _) => Some(Feedback::Wrong),
+            (Feedback::TooHigh, Some((high, n))) if n > high => Some(Feedback::TooHigh),
+            (Feedback::TooLow, Some((low, n))) if n < low => Some(Feedback::TooLow),
+            _ => None,
+        }
+    }
+}
+
+pub struct Client {
+    config: ClientConfig,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: ClientConfig) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Client { config, agent }
+    }
+
+    pub fn input_path(&self, puzzle: Puzzle) -> PathBuf {
+        input_path(&self.config.cache_dir, puzzle)
+    }
+
+    /// The input of `puzzle`, downloaded unless it is in the cache already.
+    pub fn fetch_input(&mut self, puzzle: Puzzle) -> Result<Fetched, ClientError> {
+        let path = self.input_path(puzzle);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.config.base_url, puzzle.year, puzzle.day
+        );
+        self.wait_turn()?;
+        let request = self.agent.get(&url).header("Cookie", self.cookie());
+        let content = read_reply(&url, request.call())?;
+
+        let dir = path
+            .parent()
+            .expect("inputs are in the directory of a year");
+        fs::create_dir_all(dir).map_err(|error| ClientError::Io {
+            path: dir.to_path_buf(),
+            error,
+        })?;
+        fs::write(&path, content).map_err(|error| ClientError::Io {
+            path: path.clone(),
+            error,
+        })?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Sends `answer` to `part` of `puzzle`, unless an earlier submission tells how the
+    /// site would take it. Every feedback of the site is remembered.
+    pub fn submit(
+        &mut self,
+        puzzle: Puzzle,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<Submission, ClientError> {
+        let answer = answer.to_string();
+        let path = self.submissions_path(puzzle);
+        let mut sent = load_submissions(&path)?;
+        let earlier: Vec<&Sent> = sent
+            .iter()
+            .filter(|s| s.day == puzzle.day && s.part == part.number())
+            .collect();
+        let known = earlier
+            .iter()
+            .find(|s| s.answer == answer)
+            .map(|s| s.feedback)
+            .or_else(|| earlier.iter().find_map(|s| s.implies(&answer)));
+        if let Some(feedback) = known {
+            return Ok(Submission::Known(feedback));
+        }
+
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.config.base_url, puzzle.year, puzzle.day
+        );
+        self.wait_turn()?;
+        let level = part.number().to_string();
+        let request = self.agent.post(&url).header("Cookie", self.cookie());
+        let reply = read_reply(
+            &url,
+            request.send_form([("level", level.as_str()), ("answer", answer.as_str())]),
+        )?;
+
+        let submission =
+            parse_reply(&reply).ok_or(ClientError::UnexpectedReply { url: url.clone() })?;
+        if let Submission::Sent(feedback) = submission {
+            sent.push(Sent {
+                day: puzzle.day,
+                part: part.number(),
+                answer,
+                feedback,
+            });
+            save_submissions(&path, &sent)?;
+        }
+        Ok(submission)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.config.session)
+    }
+
+    fn submissions_path(&self, puzzle: Puzzle) -> PathBuf {
+        self.config
+            .cache_dir
+            .join(puzzle.year.to_string())
+            .join(SUBMISSIONS_FILE)
+    }
+
+    /// Sleeps until [`ClientConfig::min_interval`] has passed since the last request, of
+    /// this run or an earlier one, then records the request about to be sent.
+    fn wait_turn(&mut self) -> Result<(), ClientError> {
+        let path = self.config.cache_dir.join(LAST_REQUEST_FILE);
+        if let Some(last) = load_last_request(&path)? {
+            // A time in the future, from a clock set back, waits the whole interval
+            let elapsed = SystemTime::now()
+                .duration_since(last)
+                .unwrap_or(Duration::ZERO);
+            thread::sleep(self.config.min_interval.saturating_sub(elapsed));
+        }
+        save_last_request(&path, SystemTime::now())
+    }
+}
+
+fn read_reply(
+    url: &str,
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<String, ClientError> {
+    let http = |error| ClientError::Http {
+        url: url.to_string(),
+        error,
+    };
+    match response {
+        Ok(mut response) => response.body_mut().read_to_string().map_err(http),
+        Err(ureq::Error::StatusCode(status)) => Err(ClientError::Status {
+            url: url.to_string(),
+            status,
+        }),
+        Err(error) => Err(http(error)),
+    }
+}
+
+/// What the page answering a submission says, from the sentences of the site.
+fn parse_reply(page: &str) -> Option<Submission> {
+    if page.contains("That's the right answer") {
+        Some(Submission::Sent(Feedback::Correct))
+    } else if page.contains("That's not the right answer") {
+        Some(Submission::Sent(if page.contains("too high") {
+            Feedback::TooHigh
+        } else if page.contains("too low") {
+            Feedback::TooLow
+        } else {
+            Feedback::Wrong
+        }))
+    } else if page.contains("You gave an answer too recently") {
+        Some(Submission::TooSoon(time_left(page).unwrap_or_default()))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Some(Submission::WrongLevel)
+    } else {
+        None
+    }
+}
+
+/// The wait in `You have 1m 5s left to wait`.
+fn time_left(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    page[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number = number.parse::<u64>().ok()?;
+            match unit {
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// The time in [`LAST_REQUEST_FILE`], `None` without a request yet. A file that does not
+/// hold a time is taken as no request rather than blocking every request to come.
+fn load_last_request(path: &Path) -> Result<Option<SystemTime>, ClientError> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(content
+            .trim()
+            .parse()
+            .ok()
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis))),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(ClientError::Io {
+            path: path.to_path_buf(),
+            error,
+        }),
+    }
+}
+
+fn save_last_request(path: &Path, time: SystemTime) -> Result<(), ClientError> {
+    let io_error = |error| ClientError::Io {
+        path: path.to_path_buf(),
+        error,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let millis = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    fs::write(path, millis.to_string()).map_err(io_error)
+}
+
+fn load_submissions(path: &Path) -> Result<Vec<Sent>, ClientError> {
+    match fs::read_to_string(path) {
+        Ok(content) => {
+            serde_json::from_str(&content).map_err(|e| ClientError::InvalidSubmissions {
+                path: path.to_path_buf(),
+                message: e.to_string(),
+            })
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(ClientError::Io {
+            path: path.to_path_buf(),
+            error,
+        }),
+    }
+}
+
+fn save_submissions(path: &Path, sent: &[Sent]) -> Result<(), ClientError> {
+    let io_error = |error| ClientError::Io {
+        path: path.to_path_buf(),
+        error,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let content = serde_json::to_string_pretty(sent).expect("submissions are plain data");
+    fs::write(path, content).map_err(io_error)
+}
@@ -0,0 +1,73 @@
+//! Explanations of how a day reaches its answers, line by line, for the days whose
+//! answers are hard to check by hand, such as the safe reports of 2024 day 2 or the
+//! instructions of 2024 day 3.
+
+use std::any::Any;
+use std::path::Path;
+
+use crate::runner::{self, RunError};
+use crate::{y2024, Answer, Params, Part, Puzzle, Solution, Solver};
+
+/// A day that can tell how it reaches its answers.
+pub trait Explain: Solution {
+    /// Lines explaining the answers of both parts, e.g. one per item of the input.
+    fn explain(&self, input: &Self::Input) -> Vec<String>;
+}
+
+/// Object-safe counterpart of [`Explain`], like [`Solver`] is of [`Solution`].
+pub trait Explainer: Solver {
+    /// Explanation of an input previously returned by [`Solver::parse`].
+    fn explain(&self, input: &dyn Any) -> Vec<String>;
+}
+
+impl<S> Explainer for S
+where
+    S: Explain,
+    S::Input: 'static,
+{
+    fn explain(&self, input: &dyn Any) -> Vec<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by the same solution");
+        Explain::explain(self, input)
+    }
+}
+
+pub fn get_explainer(puzzle: Puzzle, params: &Params) -> Option<Box<dyn Explainer>> {
+    match puzzle.year {
+        2024 => y2024::get_explainer(puzzle.day, params),
+        _ => None,
+    }
+}
+
+/// The explanation of a day followed by the answers it explains.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    pub lines: Vec<String>,
+    pub answers: Vec<(Part, Answer)>,
+}
+
+/// Explains `puzzle` on the input [`runner::load`] picks, and solves the parts selected by
+/// the `part` filter. `None` when the puzzle has no explanation.
+pub fn explain(
+    puzzle: Puzzle,
+    part: Option<Part>,
+    params: &Params,
+    input: Option<&Path>,
+) -> Result<Option<Explanation>, RunError> {
+    let Some(explainer) = get_explainer(puzzle, params) else {
+        return Ok(None);
+    };
+    let (_, content) = runner::load(puzzle, params, input)?;
+    let parsed = explainer.parse(&content)?;
+
+    let answers = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|p| p.is_selected(part))
+        .map(|p| (p, explainer.solve(parsed.as_ref(), p)))
+        .collect();
+    Ok(Some(Explanation {
+        lines: explainer.explain(parsed.as_ref()),
+        answers,
+    }))
+}
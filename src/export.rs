@@ -0,0 +1,251 @@
+//! Images of the last frame of a visualized day, for `run --export`: PPM, PNG or SVG,
+//! picked by the extension of the file and written without any image library.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::runner::{self, RunError};
+use crate::visualize::{get_visualizer, Color, Frame};
+use crate::{Params, Puzzle};
+
+/// Side of a tile in pixels, in every format.
+pub const TILE_PIXELS: usize = 8;
+
+/// Color of the tiles without one, such as free floor.
+const BACKGROUND: [u8; 3] = [245, 245, 240];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "ppm" => Some(ImageFormat::Ppm),
+            "png" => Some(ImageFormat::Png),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+
+    pub fn encode(self, frame: &Frame) -> Vec<u8> {
+        match self {
+            ImageFormat::Ppm => ppm(frame),
+            ImageFormat::Png => png(frame),
+            ImageFormat::Svg => svg(frame).into_bytes(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ExportError {
+    UnknownFormat { path: PathBuf },
+    Io { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::UnknownFormat { path } => write!(
+                f,
+                "cannot tell the image format of {}, expected a .ppm, .png or .svg file",
+                path.display()
+            ),
+            ExportError::Io { path, error } => {
+                write!(f, "cannot write {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ExportError::UnknownFormat { .. } => None,
+            ExportError::Io { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Writes the last frame of `puzzle` on the input [`runner::load`] picks to `path`, e.g.
+/// the Christmas tree of 2024 day 14. `false` when the puzzle has no visualization.
+pub fn export(
+    puzzle: Puzzle,
+    params: &Params,
+    input: Option<&Path>,
+    path: &Path,
+) -> Result<bool, RunError> {
+    let format = ImageFormat::from_path(path).ok_or_else(|| ExportError::UnknownFormat {
+        path: path.to_path_buf(),
+    })?;
+    let Some(visualizer) = get_visualizer(puzzle, params) else {
+        return Ok(false);
+    };
+    let (_, content) = runner::load(puzzle, params, input)?;
+    let parsed = visualizer.parse(&content)?;
+    let frame = visualizer
+        .frames(parsed.as_ref())
+        .last()
+        .expect("visualizations have at least one frame");
+
+    fs::write(path, format.encode(&frame)).map_err(|error| ExportError::Io {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    Ok(true)
+}
+
+fn tile_rgb(color: Option<Color>) -> [u8; 3] {
+    color.map_or(BACKGROUND, Color::rgb)
+}
+
+/// Rows of RGB pixels, each tile filling a square of [`TILE_PIXELS`].
+fn pixel_rows(frame: &Frame) -> Vec<Vec<u8>> {
+    let tiles: Vec<[u8; 3]> = frame
+        .grid
+        .iter()
+        .map(|(_, tile)| tile_rgb(tile.color))
+        .collect();
+    tiles
+        .chunks(frame.grid.width().max(1))
+        .flat_map(|line| {
+            let row: Vec<u8> = line
+                .iter()
+                .flat_map(|rgb| rgb.repeat(TILE_PIXELS))
+                .collect();
+            std::iter::repeat_n(row, TILE_PIXELS)
+        })
+        .collect()
+}
+
+/// Binary PPM (P6), the simplest format to write and one most viewers open.
+pub fn ppm(frame: &Frame) -> Vec<u8> {
+    let mut image = format!(
+        "P6\n{} {}\n255\n",
+        frame.grid.width() * TILE_PIXELS,
+        frame.grid.height() * TILE_PIXELS
+    )
+    .into_bytes();
+    pixel_rows(frame)
+        .into_iter()
+        .for_each(|row| image.extend(row));
+    image
+}
+
+/// 8-bit RGB PNG, its pixels in stored (uncompressed) deflate blocks.
+pub fn png(frame: &Frame) -> Vec<u8> {
+    // Each row starts with its filter type, none
+    let mut pixels = Vec::new();
+    for row in pixel_rows(frame) {
+        pixels.push(0);
+        pixels.extend(row);
+    }
+
+    let mut header = Vec::with_capacity(13);
+    header.extend(((frame.grid.width() * TILE_PIXELS) as u32).to_be_bytes());
+    header.extend(((frame.grid.height() * TILE_PIXELS) as u32).to_be_bytes());
+    // Bit depth 8, RGB, deflate, no filter, no interlace
+    header.extend([8, 2, 0, 0, 0]);
+
+    let mut image = b"\x89PNG\r\n\x1a\n".to_vec();
+    png_chunk(&mut image, b"IHDR", &header);
+    png_chunk(&mut image, b"IDAT", &zlib_stored(&pixels));
+    png_chunk(&mut image, b"IEND", &[]);
+    image
+}
+
+fn png_chunk(image: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    image.extend((data.len() as u32).to_be_bytes());
+    let start = image.len();
+    image.extend(kind);
+    image.extend(data);
+    let crc = crc32(&image[start..]);
+    image.extend(crc.to_be_bytes());
+}
+
+/// A zlib stream of `data` in deflate blocks without compression.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+
+    let mut stream = vec![0x78, 0x01];
+    let blocks = data.len().div_ceil(MAX_BLOCK).max(1);
+    for index in 0..blocks {
+        let block = &data[index * MAX_BLOCK..((index + 1) * MAX_BLOCK).min(data.len())];
+        let last = index + 1 == blocks;
+        stream.push(u8::from(last));
+        stream.extend((block.len() as u16).to_le_bytes());
+        stream.extend((!(block.len() as u16)).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+/// One square per colored tile over a background, with the caption as title.
+pub fn svg(frame: &Frame) -> String {
+    let (width, height) = (frame.grid.width(), frame.grid.height());
+    let hex = |[r, g, b]: [u8; 3]| format!("#{:02x}{:02x}{:02x}", r, g, b);
+    let mut image = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+         viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+        width * TILE_PIXELS,
+        height * TILE_PIXELS,
+        width,
+        height
+    );
+    image.push_str(&format!("<title>{}</title>\n", escape_xml(&frame.caption)));
+    image.push_str(&format!(
+        "<rect width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+        width,
+        height,
+        hex(BACKGROUND)
+    ));
+    for (coordinate, tile) in frame.grid.iter() {
+        if let Some(color) = tile.color {
+            image.push_str(&format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"{}\"/>\n",
+                coordinate.column,
+                coordinate.line,
+                hex(color.rgb())
+            ));
+        }
+    }
+    image.push_str("</svg>\n");
+    image
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,872 @@
+//! Random inputs of the 2024 puzzles of a chosen size, to look for panics and scaling cliffs in the
+//! solvers. Every generator sticks to what the puzzle guarantees about its inputs (a
+//! guard that leaves the lab, a racetrack without branches, a single largest clique...)
+//! so that a solver failing on one of them is a bug of the solver.
+//!
+//! What `size` stands for depends on the day:
+//!
+//! - the number of lines for days 1, 2, 7 and 21, and of lines of updates for day 5;
+//! - the number of instructions for day 3;
+//! - the side of the map for days 4, 6, 8, 10, 12, 15, 16 and 20, the racetrack of day
+//!   20 growing until cheating through a wall saves `--save` picoseconds;
+//! - the number of files on the disk for day 9;
+//! - the number of stones, machines, robots, designs, buyers or schematics for days 11,
+//!   13, 14, 19, 22 and 25;
+//! - the number of outputs of the program for day 17, whose own copy needs at most 8;
+//! - the side of the memory space for day 18, unless given by the parameters;
+//! - the number of computers for day 23;
+//! - the number of bits of the adder for day 24.
+//!
+//! Sizes are clamped to what makes sense for the day, such as a side of at least 3 for
+//! the lab of day 6.
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::ops::RangeInclusive;
+
+use crate::geometry::{Direction4, Vec2};
+use crate::grid::Grid;
+use crate::reference::run_program;
+use crate::y2024::day20;
+use crate::{Params, Puzzle};
+
+/// SplitMix64: small, fast and fully determined by its seed, so that a seed gives the
+/// same input on every platform.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        let span = end.abs_diff(start) + 1;
+        start.wrapping_add((self.next_u64() % span) as i64)
+    }
+
+    /// An index below `n`, which must not be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Generates an input of `puzzle`, the same `seed` always giving the same input. The
+/// parameters of the solver, such as the grid of day 14, shape the input the same way
+/// they shape the solving. `None` when `puzzle` has no generator.
+pub fn generate(puzzle: Puzzle, size: usize, seed: u64, params: &Params) -> Option<String> {
+    let generator: fn(&mut Rng, usize, &Params) -> String = match (puzzle.year, puzzle.day) {
+        (2024, 1) => location_ids,
+        (2024, 2) => reports,
+        (2024, 3) => corrupted_memory,
+        (2024, 4) => word_search,
+        (2024, 5) => safety_manual,
+        (2024, 6) => lab,
+        (2024, 7) => calibration_equations,
+        (2024, 8) => antenna_map,
+        (2024, 9) => disk_map,
+        (2024, 10) => topographic_map,
+        (2024, 11) => stones,
+        (2024, 12) => garden,
+        (2024, 13) => claw_machines,
+        (2024, 14) => bathroom_robots,
+        (2024, 15) => warehouse,
+        (2024, 16) => reindeer_maze,
+        (2024, 17) => computer,
+        (2024, 18) => falling_bytes,
+        (2024, 19) => towels,
+        (2024, 20) => racetrack,
+        (2024, 21) => door_codes,
+        (2024, 22) => buyers,
+        (2024, 23) => lan_party,
+        (2024, 24) => adder,
+        (2024, 25) => schematics,
+        _ => return None,
+    };
+    Some(generator(&mut Rng::new(seed), size.max(1), params))
+}
+
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().collect::<Vec<_>>().join("\n")
+}
+
+/// Renders a grid without the newline after its last line, as in the puzzle inputs.
+fn render(grid: &Grid<char>) -> String {
+    grid.to_string().trim_end().to_string()
+}
+
+fn location_ids(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let left: Vec<i64> = (0..size).map(|_| rng.range(10000..=99999)).collect();
+    // Some of the right IDs also appear in the left list, for the similarity score
+    lines(left.iter().map(|l| {
+        let r = if rng.chance(0.5) {
+            *rng.choose(&left)
+        } else {
+            rng.range(10000..=99999)
+        };
+        format!("{}   {}", l, r)
+    }))
+}
+
+fn reports(rng: &mut Rng, size: usize, _: &Params) -> String {
+    lines((0..size).map(|_| {
+        let sign = if rng.chance(0.5) { 1 } else { -1 };
+        let mut level = rng.range(30..=70);
+        let mut levels: Vec<i64> = (0..rng.range(5..=8))
+            .map(|_| {
+                level += sign * rng.range(1..=3);
+                level
+            })
+            .collect();
+        // About half of the reports are unsafe, some of them by a single level
+        if rng.chance(0.5) {
+            let index = rng.below(levels.len());
+            levels[index] = rng.range(1..=99);
+        }
+        levels
+            .iter()
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }))
+}
+
+fn corrupted_memory(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let junk: Vec<char> = "!@#$%^&*()[]{}<>,;:'+-_ ?/fromwhatselectwhy"
+        .chars()
+        .collect();
+    let mut memory = String::new();
+    for i in 0..size {
+        for _ in 0..rng.range(0..=6) {
+            memory.push(*rng.choose(&junk));
+        }
+        let (a, b) = (rng.range(1..=999), rng.range(1..=999));
+        let instruction = match rng.below(8) {
+            0 => "do()".to_string(),
+            1 => "don't()".to_string(),
+            2 => format!("mul({},{}]", a, b),
+            3 => format!("mul ( {} , {} )", a, b),
+            4 => format!("mul({}*{})", a, b),
+            _ => format!("mul({},{})", a, b),
+        };
+        memory.push_str(&instruction);
+        if i % 50 == 49 {
+            memory.push('\n');
+        }
+    }
+    memory.trim_end().to_string()
+}
+
+fn word_search(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let letters: Vec<char> = "XMAS".chars().collect();
+    render(&Grid::from_fn(size, size, |_| *rng.choose(&letters)))
+}
+
+/// Rules between every pair of 49 pages, as in the puzzle, so that the pages of any
+/// update have a single correct order.
+fn safety_manual(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let mut pages: Vec<i64> = (11..=99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules: Vec<String> = pages
+        .iter()
+        .enumerate()
+        .flat_map(|(i, before)| {
+            pages[i + 1..]
+                .iter()
+                .map(move |after| format!("{}|{}", before, after))
+        })
+        .collect();
+    rng.shuffle(&mut rules);
+
+    let updates = (0..size).map(|_| {
+        let length = 2 * rng.below(10) + 5;
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(length);
+        // Half of the updates are already in the right order
+        if rng.chance(0.5) {
+            update.sort_by_key(|page| pages.iter().position(|p| p == page));
+        }
+        update
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    });
+
+    format!("{}\n\n{}", rules.join("\n"), lines(updates))
+}
+
+/// A lab the guard eventually leaves, the puzzle not saying what to do otherwise.
+fn lab(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let side = size.max(3);
+    loop {
+        let mut map = Grid::from_fn(side, side, |_| if rng.chance(0.05) { '#' } else { '.' });
+        let mut guard = Vec2::new(rng.below(side) as i64, rng.below(side) as i64);
+        let mut direction = *rng.choose(&Direction4::ALL);
+        map[guard] = direction.to_char();
+        let map = map;
+
+        let mut seen = HashSet::new();
+        while seen.insert((guard, direction)) {
+            match map.get(guard + direction.offset()) {
+                None => return render(&map),
+                Some('#') => direction = direction.turn_right(),
+                Some(_) => guard += direction.offset(),
+            }
+        }
+    }
+}
+
+fn calibration_equations(rng: &mut Rng, size: usize, _: &Params) -> String {
+    lines((0..size).map(|_| {
+        let numbers: Vec<u64> = (0..rng.range(2..=12))
+            .map(|_| rng.range(1..=999) as u64)
+            .collect();
+        // Results stay far below u64::MAX, which concatenation would otherwise reach
+        let mut result = numbers[0];
+        for &n in &numbers[1..] {
+            let concatenated = format!("{}{}", result, n).parse::<u64>().ok();
+            result = match rng.below(3) {
+                0 => result * n,
+                1 => concatenated.unwrap_or(u64::MAX),
+                _ => result + n,
+            };
+            if result > 1_000_000_000_000_000 {
+                result = numbers[0] + n;
+            }
+        }
+        // Some equations cannot be true
+        if rng.chance(0.3) {
+            result += 1;
+        }
+        let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+        format!("{}: {}", result, numbers.join(" "))
+    }))
+}
+
+fn antenna_map(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let frequencies: Vec<char> = "0123456789abcdefghijABCDEFGHIJ".chars().collect();
+    render(&Grid::from_fn(size, size, |_| {
+        if rng.chance(0.02) {
+            *rng.choose(&frequencies)
+        } else {
+            '.'
+        }
+    }))
+}
+
+fn disk_map(rng: &mut Rng, size: usize, _: &Params) -> String {
+    (0..size)
+        .flat_map(|file| {
+            let free = (file + 1 < size).then(|| rng.range(0..=9));
+            [Some(rng.range(1..=9)), free]
+        })
+        .flatten()
+        .map(|digit| digit.to_string())
+        .collect()
+}
+
+/// Random heights crossed by `size` hiking trails climbing from 0 to 9.
+fn topographic_map(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let side = size.max(3);
+    let mut map = Grid::from_fn(side, side, |_| rng.range(0..=9));
+    for _ in 0..size {
+        let mut position = Vec2::new(rng.below(side) as i64, rng.below(side) as i64);
+        map[position] = 0;
+        for height in 1..=9 {
+            let neighbors: Vec<Vec2> = map.neighbors4(position).collect();
+            position = *rng.choose(&neighbors);
+            map[position] = height;
+        }
+    }
+    map.to_string().trim_end().to_string()
+}
+
+fn stones(rng: &mut Rng, size: usize, _: &Params) -> String {
+    (0..size)
+        .map(|_| rng.range(0..=999_999).to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Plants mostly copy a neighbor already placed, growing regions of various shapes.
+fn garden(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let mut garden = Grid::new(size, size, 'A');
+    for coordinate in garden.coordinates().collect::<Vec<_>>() {
+        let placed: Vec<char> = [Direction4::Up, Direction4::Left]
+            .into_iter()
+            .filter_map(|d| garden.get(coordinate + d.offset()).copied())
+            .collect();
+        garden[coordinate] = if !placed.is_empty() && rng.chance(0.8) {
+            *rng.choose(&placed)
+        } else {
+            (b'A' + rng.below(26) as u8) as char
+        };
+    }
+    render(&garden)
+}
+
+fn claw_machines(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let machines = (0..size).map(|i| {
+        // The first prize and some others can be won once moved by the offset of part 2
+        let far = i == 0 || rng.chance(0.4);
+        // Buttons are never collinear, so that a prize is reached in at most one way
+        let (a, b, far_prize) = loop {
+            let a = (rng.range(10..=99), rng.range(10..=99));
+            let b = (rng.range(10..=99), rng.range(10..=99));
+            if a.0 * b.1 == a.1 * b.0 {
+                continue;
+            }
+            let target = (rng.range(1000..=20000), rng.range(1000..=20000));
+            match far_prize(a, b, target) {
+                None if far => continue,
+                prize => break (a, b, prize.filter(|_| far)),
+            }
+        };
+        let (pushes_a, pushes_b) = (rng.range(0..=100), rng.range(0..=100));
+        let mut prize = (
+            pushes_a * a.0 + pushes_b * b.0,
+            pushes_a * a.1 + pushes_b * b.1,
+        );
+        // Others cannot be won at all
+        if let Some(far_prize) = far_prize {
+            prize = far_prize;
+        } else if rng.chance(0.4) {
+            prize.0 += rng.range(1..=20);
+        }
+        format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        )
+    });
+    machines.collect::<Vec<_>>().join("\n\n")
+}
+
+/// A prize near `target` that whole numbers of pushes of `a` and `b` reach once moved by
+/// the offset of part 2 of day 13, when the offset lies between the two buttons. `None`
+/// as well when they reach it without the offset, which would take more than the 100
+/// pushes part 1 allows.
+fn far_prize(a: (i64, i64), b: (i64, i64), target: (i64, i64)) -> Option<(i64, i64)> {
+    const OFFSET: i128 = 10_000_000_000_000;
+    let [ax, ay, bx, by] = [a.0, a.1, b.0, b.1].map(i128::from);
+    let (tx, ty) = (OFFSET + i128::from(target.0), OFFSET + i128::from(target.1));
+    let determinant = ax * by - ay * bx;
+    let pushes_a = (tx * by - ty * bx) / determinant;
+    let pushes_b = (ax * ty - ay * tx) / determinant;
+    if pushes_a < 0 || pushes_b < 0 {
+        return None;
+    }
+    let prize = (
+        pushes_a * ax + pushes_b * bx - OFFSET,
+        pushes_a * ay + pushes_b * by - OFFSET,
+    );
+    let near_a = prize.0 * by - prize.1 * bx;
+    let near_b = ax * prize.1 - ay * prize.0;
+    let near = near_a % determinant == 0 && near_b % determinant == 0;
+    (prize.0 >= 0 && prize.1 >= 0 && !near).then_some((prize.0 as i64, prize.1 as i64))
+}
+
+/// Robots on the 101x103 bathroom of the puzzle unless `--width` and `--height` say
+/// otherwise. They start on distinct tiles: all of them are back there after
+/// `width * height` seconds, so the search for the tree always ends.
+fn bathroom_robots(rng: &mut Rng, size: usize, params: &Params) -> String {
+    let width = params.or_default(params.width, 11, 101).max(1);
+    let height = params.or_default(params.height, 7, 103).max(1);
+    let count = size.min((width * height) as usize);
+
+    let mut positions = Vec::new();
+    let mut taken: HashSet<(i64, i64)> = HashSet::new();
+    while positions.len() < count {
+        let position = (rng.range(0..=width - 1), rng.range(0..=height - 1));
+        if taken.insert(position) {
+            positions.push(position);
+        }
+    }
+    rng.shuffle(&mut positions);
+
+    lines(positions.into_iter().map(|(x, y)| {
+        let (vx, vy) = (rng.range(-99..=99), rng.range(-99..=99));
+        format!("p={},{} v={},{}", x, y, vx, vy)
+    }))
+}
+
+fn warehouse(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let side = size.max(3) as i64;
+    let mut map = Grid::from_fn(side as usize, side as usize, |c| {
+        if c.line == 0 || c.column == 0 || c.line == side - 1 || c.column == side - 1 {
+            '#'
+        } else {
+            match rng.below(20) {
+                0 => '#',
+                1..=6 => 'O',
+                _ => '.',
+            }
+        }
+    });
+    map[Vec2::new(rng.range(1..=side - 2), rng.range(1..=side - 2))] = '@';
+
+    let moves: Vec<char> = (0..size * 10)
+        .map(|_| rng.choose(&Direction4::ALL).to_char())
+        .collect();
+    let moves = moves.chunks(70).map(|line| line.iter().collect::<String>());
+
+    format!("{}\n\n{}", render(&map), lines(moves))
+}
+
+/// Carves a perfect maze, with a single path between any two of its cells, on the odd
+/// coordinates of a map with an odd `side`.
+fn carve_maze(rng: &mut Rng, side: usize) -> Grid<bool> {
+    let mut open = Grid::new(side, side, false);
+    let interior = 1..side as i64 - 1;
+    let start = Vec2::new(1, 1);
+    open[start] = true;
+
+    let mut stack = vec![start];
+    while let Some(&cell) = stack.last() {
+        let unvisited: Vec<Direction4> = Direction4::ALL
+            .into_iter()
+            .filter(|d| {
+                let next = cell + d.offset() * 2;
+                interior.contains(&next.line) && interior.contains(&next.column) && !open[next]
+            })
+            .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let direction = *rng.choose(&unvisited);
+        open[cell + direction.offset()] = true;
+        open[cell + direction.offset() * 2] = true;
+        stack.push(cell + direction.offset() * 2);
+    }
+
+    open
+}
+
+/// Shortest paths from `start` through the open tiles, as the previous tile of every
+/// reached tile.
+fn breadth_first(open: &Grid<bool>, start: Vec2) -> HashMap<Vec2, Vec2> {
+    let mut previous = HashMap::from([(start, start)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(tile) = queue.pop_front() {
+        for next in open.neighbors4(tile) {
+            if open[next] && !previous.contains_key(&next) {
+                previous.insert(next, tile);
+                queue.push_back(next);
+            }
+        }
+    }
+    previous
+}
+
+fn odd_side(size: usize) -> usize {
+    size.max(5) | 1
+}
+
+/// A maze with a few loops, from the bottom left corner to the top right one.
+fn reindeer_maze(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let side = odd_side(size);
+    let open = carve_maze(rng, side);
+    let last = side as i64 - 1;
+    let mut maze = Grid::from_fn(side, side, |c| {
+        // Walls between two cells of the maze, the only ones that open a loop
+        let between = (c.line + c.column) % 2 == 1;
+        let inside = c.line > 0 && c.column > 0 && c.line < last && c.column < last;
+        if open[c] || (between && inside && rng.chance(0.1)) {
+            '.'
+        } else {
+            '#'
+        }
+    });
+    maze[Vec2::new(last - 1, 1)] = 'S';
+    maze[Vec2::new(1, last - 1)] = 'E';
+    render(&maze)
+}
+
+/// A track without any branch, as in the puzzle: the path of a maze from its bottom
+/// left corner to the farthest cell. The maze grows past `size` until a cheat through a
+/// single wall saves the picoseconds of `--save`, so that both parts count some cheats.
+fn racetrack(rng: &mut Rng, size: usize, params: &Params) -> String {
+    let save = day20::picoseconds_to_save(params) as usize;
+    let mut side = odd_side(size);
+    loop {
+        let open = carve_maze(rng, side);
+        let start = Vec2::new(side as i64 - 2, 1);
+        let previous = breadth_first(&open, start);
+        let end = *previous
+            .keys()
+            .max_by_key(|&&tile| (start.manhattan(tile), tile))
+            .expect("the start is reached");
+        // From the end back to the start
+        let path: Vec<Vec2> =
+            std::iter::successors(Some(end), |&tile| (tile != start).then(|| previous[&tile]))
+                .collect();
+
+        let cheat = path.iter().enumerate().any(|(i, &tile)| {
+            path.iter()
+                .skip(i + save + 2)
+                .any(|&other| tile.manhattan(other) == 2)
+        });
+        if cheat {
+            let mut track = Grid::new(side, side, '#');
+            for &tile in &path {
+                track[tile] = '.';
+            }
+            track[start] = 'S';
+            track[end] = 'E';
+            return render(&track);
+        }
+        side += 2;
+    }
+}
+
+/// Smallest value of register A making `program` output itself, found 3 bits at a
+/// time from the end of the program as each loop shifts A by 3 bits.
+fn quine(program: &[u64], suffix: usize, a: u64) -> Option<u64> {
+    if suffix == program.len() {
+        return Some(a);
+    }
+    (0..8).find_map(|bits| {
+        let candidate = a * 8 + bits;
+        (run_program(program, [candidate, 0, 0]) == program[program.len() - suffix - 1..])
+            .then(|| quine(program, suffix + 1, candidate))
+            .flatten()
+    })
+}
+
+/// A program looping over register A 3 bits at a time like the ones of the puzzle, and
+/// which has a value of A making it output itself. It has 4 instructions instead of 8,
+/// so that this value stays within reach of the brute force of [`reference::Day17`].
+///
+/// [`reference::Day17`]: crate::reference::Day17
+fn computer(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let program = loop {
+        // B or C computed from what is left of A, then output
+        let (instruction, output) = *rng.choose(&[(2, 5), (6, 5), (7, 6)]);
+        let operand = if instruction == 2 {
+            4
+        } else {
+            rng.range(0..=6)
+        };
+        let program: Vec<u64> = [0, 3, instruction, operand, 5, output, 3, 0]
+            .iter()
+            .map(|&n| n as u64)
+            .collect();
+        if quine(&program, 0, 0).is_some() {
+            break program;
+        }
+    };
+    // One output for each group of 3 bits of A, as many as an i64 holds
+    let digits = size.clamp(1, 20) as u32;
+    let a = rng.range(8i64.pow(digits - 1)..=8i64.pow(digits) - 1);
+    let program: Vec<String> = program.iter().map(|n| n.to_string()).collect();
+
+    format!(
+        "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}",
+        a,
+        program.join(",")
+    )
+}
+
+/// Every byte but the corners falls, those off a random path last so that the first
+/// `--bytes` of them leave a way out. Without `--width` and `--height`, the memory
+/// space is `size` wide, or wider if needed to hold those bytes.
+fn falling_bytes(rng: &mut Rng, size: usize, params: &Params) -> String {
+    let bytes = params.or_default(params.bytes, 12, 1024) as i64;
+    let mut side = (size as i64).max(2);
+    while side * side - (2 * side - 1) < bytes {
+        side += 1;
+    }
+    let width = params.width.unwrap_or(side).max(2);
+    let height = params.height.unwrap_or(side).max(2);
+
+    // Staircase from the top left corner to the bottom right one
+    let mut path = HashSet::from([Vec2::ZERO]);
+    let mut position = Vec2::ZERO;
+    while position != Vec2::new(height - 1, width - 1) {
+        let down = position.line < height - 1
+            && (position.column == width - 1
+                || rng.chance(height as f64 / (width + height) as f64));
+        position += if down {
+            Direction4::Down.offset()
+        } else {
+            Direction4::Right.offset()
+        };
+        path.insert(position);
+    }
+
+    let memory = Grid::new(width as usize, height as usize, ());
+    let (mut on_path, mut off_path): (Vec<Vec2>, Vec<Vec2>) =
+        memory.coordinates().partition(|c| path.contains(c));
+    on_path.retain(|&c| c != Vec2::ZERO && c != position);
+    rng.shuffle(&mut off_path);
+    rng.shuffle(&mut on_path);
+
+    lines(
+        off_path
+            .into_iter()
+            .chain(on_path)
+            .map(|c| format!("{},{}", c.column, c.line)),
+    )
+}
+
+/// Patterns miss one of the single stripes, so that some designs cannot be made.
+fn towels(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let colors: Vec<char> = "wubrg".chars().collect();
+    let stripes = |rng: &mut Rng, length: i64| -> String {
+        (0..length).map(|_| *rng.choose(&colors)).collect()
+    };
+
+    let missing = *rng.choose(&colors);
+    let mut patterns: Vec<String> = colors
+        .iter()
+        .filter(|&&c| c != missing)
+        .map(|c| c.to_string())
+        .collect();
+    let mut seen: HashSet<String> = patterns.iter().cloned().collect();
+    while patterns.len() < 40 {
+        let length = rng.range(2..=8);
+        let pattern = stripes(rng, length);
+        if seen.insert(pattern.clone()) {
+            patterns.push(pattern);
+        }
+    }
+    rng.shuffle(&mut patterns);
+
+    let designs = (0..size).map(|_| {
+        let length = rng.range(20..=50) as usize;
+        if rng.chance(0.7) {
+            let mut design = String::new();
+            while design.len() < length {
+                let pattern: &String = rng.choose(&patterns);
+                design.push_str(pattern);
+            }
+            design
+        } else {
+            stripes(rng, length as i64)
+        }
+    });
+
+    format!("{}\n\n{}", patterns.join(", "), lines(designs))
+}
+
+fn door_codes(rng: &mut Rng, size: usize, _: &Params) -> String {
+    lines((0..size).map(|_| format!("{:03}A", rng.range(0..=999))))
+}
+
+fn buyers(rng: &mut Rng, size: usize, _: &Params) -> String {
+    lines((0..size).map(|_| rng.range(1..=16_777_215).to_string()))
+}
+
+/// A sparse network without triangles of its own, in which a clique of up to 13
+/// computers, one of them starting with `t`, is planted. Other computers link to at
+/// most one computer of the clique, so it is the single largest one, as the puzzle
+/// guarantees.
+fn lan_party(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let mut names: Vec<String> = (b'a'..=b'z')
+        .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+        .collect();
+    rng.shuffle(&mut names);
+    let chief = names
+        .iter()
+        .position(|n| n.starts_with('t'))
+        .expect("26 of them");
+    names.swap(0, chief);
+    names.truncate(size.clamp(16, names.len()));
+
+    let clique_size = (names.len() / 4).clamp(4, 13);
+    let (clique, others) = names.split_at(clique_size);
+    let mut links: BTreeSet<(usize, usize)> = BTreeSet::new();
+    let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::new(); names.len()];
+    let mut link = |a: usize, b: usize, neighbors: &mut Vec<HashSet<usize>>| {
+        links.insert((a.min(b), a.max(b)));
+        neighbors[a].insert(b);
+        neighbors[b].insert(a);
+    };
+
+    for a in 0..clique.len() {
+        for b in a + 1..clique.len() {
+            link(a, b, &mut neighbors);
+        }
+    }
+    for other in clique.len()..names.len() {
+        if rng.chance(0.5) {
+            link(other, rng.below(clique.len()), &mut neighbors);
+        }
+        for _ in 0..3 {
+            let peer = clique.len() + rng.below(others.len());
+            let shares_neighbor = neighbors[other].iter().any(|n| neighbors[peer].contains(n));
+            if peer != other && !shares_neighbor {
+                link(other, peer, &mut neighbors);
+            }
+        }
+    }
+
+    let mut links: Vec<String> = links
+        .into_iter()
+        .map(|(a, b)| {
+            let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+            format!("{}-{}", names[a], names[b])
+        })
+        .collect();
+    rng.shuffle(&mut links);
+    lines(links)
+}
+
+struct Gate {
+    operands: [String; 2],
+    operator: &'static str,
+    output: String,
+}
+
+/// Whether every gate eventually gets both its inputs, as a swap can make a loop.
+fn settles(wires: &HashSet<String>, gates: &[Gate]) -> bool {
+    let mut known = wires.clone();
+    let mut pending: Vec<&Gate> = gates.iter().collect();
+    while !pending.is_empty() {
+        let before = pending.len();
+        pending.retain(|gate| {
+            let ready = gate.operands.iter().all(|o| known.contains(o));
+            if ready {
+                known.insert(gate.output.clone());
+            }
+            !ready
+        });
+        if pending.len() == before {
+            return false;
+        }
+    }
+    true
+}
+
+/// A ripple-carry adder of `size` bits, with `--swaps` pairs of gates (2 in the example,
+/// 4 in the puzzle) having their outputs swapped without making a loop.
+fn adder(rng: &mut Rng, size: usize, params: &Params) -> String {
+    // The solver reads the result as a u64
+    let bits = size.clamp(2, 63);
+    let mut taken = HashSet::new();
+    let mut wire = |rng: &mut Rng| loop {
+        let name: String = (0..3)
+            .map(|i| (b'a' + rng.below(if i == 0 { 23 } else { 26 }) as u8) as char)
+            .collect();
+        if taken.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    let x = |bit: usize| format!("x{:02}", bit);
+    let y = |bit: usize| format!("y{:02}", bit);
+    let z = |bit: usize| format!("z{:02}", bit);
+    let gate = |a: String, operator, b: String, output: String| Gate {
+        operands: [a, b],
+        operator,
+        output,
+    };
+    let mut gates = vec![
+        gate(x(0), "XOR", y(0), z(0)),
+        gate(x(0), "AND", y(0), wire(rng)),
+    ];
+    let mut carry = gates[1].output.clone();
+    for bit in 1..bits {
+        let (sum, both, carried) = (wire(rng), wire(rng), wire(rng));
+        let next_carry = if bit == bits - 1 { z(bits) } else { wire(rng) };
+        gates.extend([
+            gate(x(bit), "XOR", y(bit), sum.clone()),
+            gate(x(bit), "AND", y(bit), both.clone()),
+            gate(sum.clone(), "XOR", carry.clone(), z(bit)),
+            gate(sum, "AND", carry, carried.clone()),
+            gate(both, "OR", carried, next_carry.clone()),
+        ]);
+        carry = next_carry;
+    }
+
+    let wires: Vec<String> = (0..bits).map(x).chain((0..bits).map(y)).collect();
+    let known: HashSet<String> = wires.iter().cloned().collect();
+    let swaps = params.or_default(params.swaps, 2, 4).min(gates.len() / 2);
+    let mut swapped = HashSet::new();
+    let mut attempts = 0;
+    while swapped.len() < swaps * 2 && attempts < 1000 {
+        attempts += 1;
+        let (a, b) = (rng.below(gates.len()), rng.below(gates.len()));
+        if a == b || swapped.contains(&a) || swapped.contains(&b) {
+            continue;
+        }
+        let output = gates[a].output.clone();
+        gates[a].output = std::mem::replace(&mut gates[b].output, output);
+        if settles(&known, &gates) {
+            swapped.extend([a, b]);
+        } else {
+            let output = gates[a].output.clone();
+            gates[a].output = std::mem::replace(&mut gates[b].output, output);
+        }
+    }
+
+    rng.shuffle(&mut gates);
+    let values = wires
+        .iter()
+        .map(|w| format!("{}: {}", w, rng.below(2)))
+        .collect::<Vec<_>>();
+    let gates = gates.into_iter().map(|mut gate| {
+        if rng.chance(0.5) {
+            gate.operands.swap(0, 1);
+        }
+        format!(
+            "{} {} {} -> {}",
+            gate.operands[0], gate.operator, gate.operands[1], gate.output
+        )
+    });
+
+    format!("{}\n\n{}", values.join("\n"), lines(gates))
+}
+
+fn schematics(rng: &mut Rng, size: usize, _: &Params) -> String {
+    let schematics = (0..size).map(|_| {
+        let heights: Vec<i64> = (0..5).map(|_| rng.range(0..=5)).collect();
+        let lock = rng.chance(0.5);
+        let rows = (0..7).map(|row| {
+            heights
+                .iter()
+                .map(|&height| {
+                    let filled = if lock {
+                        row <= height
+                    } else {
+                        row >= 6 - height
+                    };
+                    if filled {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        });
+        lines(rows)
+    });
+    schematics.collect::<Vec<_>>().join("\n\n")
+}
@@ -0,0 +1,210 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or an offset on a 2D map, `line` going down and `column` going right.
+/// Signed so that stepping off a map or taking the difference of two positions never
+/// underflows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub line: i64,
+    pub column: i64,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    pub const fn new(line: i64, column: i64) -> Self {
+        Vec2 { line, column }
+    }
+
+    /// Number of orthogonal steps between two positions.
+    pub fn manhattan(self, other: Vec2) -> u64 {
+        self.line.abs_diff(other.line) + self.column.abs_diff(other.column)
+    }
+
+    /// Number of king moves, diagonal ones included, between two positions.
+    pub fn chebyshev(self, other: Vec2) -> u64 {
+        self.line
+            .abs_diff(other.line)
+            .max(self.column.abs_diff(other.column))
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.line + other.line, self.column + other.column)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.line - other.line, self.column - other.column)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: i64) -> Vec2 {
+        Vec2::new(self.line * factor, self.column * factor)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.line, -self.column)
+    }
+}
+
+/// One of the 4 orthogonal directions, as drawn on the maps of the puzzles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// Clockwise from `Up`
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// Reads an arrow among `^>v<`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction4::Up),
+            '>' => Some(Direction4::Right),
+            'v' => Some(Direction4::Down),
+            '<' => Some(Direction4::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction4::Up => '^',
+            Direction4::Right => '>',
+            Direction4::Down => 'v',
+            Direction4::Left => '<',
+        }
+    }
+
+    /// Offset of a single step.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction4::Up => Vec2::new(-1, 0),
+            Direction4::Right => Vec2::new(0, 1),
+            Direction4::Down => Vec2::new(1, 0),
+            Direction4::Left => Vec2::new(0, -1),
+        }
+    }
+
+    /// Quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl From<Direction4> for Vec2 {
+    fn from(direction: Direction4) -> Vec2 {
+        direction.offset()
+    }
+}
+
+/// One of the 8 compass directions, diagonals included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise from `Up`
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Offset of a single step.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction8::Up => Vec2::new(-1, 0),
+            Direction8::UpRight => Vec2::new(-1, 1),
+            Direction8::Right => Vec2::new(0, 1),
+            Direction8::DownRight => Vec2::new(1, 1),
+            Direction8::Down => Vec2::new(1, 0),
+            Direction8::DownLeft => Vec2::new(1, -1),
+            Direction8::Left => Vec2::new(0, -1),
+            Direction8::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+
+    /// Eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Direction8 {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl From<Direction8> for Vec2 {
+    fn from(direction: Direction8) -> Vec2 {
+        direction.offset()
+    }
+}
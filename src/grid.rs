@@ -0,0 +1,173 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::{Direction4, Direction8, Vec2};
+use crate::parse::{lines, Source};
+use crate::ParseError;
+
+/// A dense rectangular grid, stored line by line.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Vec2) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|line| (0..width).map(move |column| (line, column)))
+            .map(|(line, column)| cell(Vec2::new(line as i64, column as i64)))
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses `text`, a slice of the `source` input, one line of text per line of the grid.
+    /// `cell` maps each character to a cell, `None` rejecting it with an error saying what
+    /// was `expected` instead.
+    pub fn parse(
+        source: &Source,
+        text: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in lines(text) {
+            let line_width = line.chars().count();
+            if *width.get_or_insert(line_width) != line_width {
+                return Err(source.error(
+                    line,
+                    format!("expected a line of {} cells", width.unwrap_or(0)),
+                ));
+            }
+            for (offset, c) in line.char_indices() {
+                cells.push(cell(c).ok_or_else(|| source.unexpected_char(line, offset, expected))?);
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(source.missing(text, "a grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset(&self, coordinate: Vec2) -> Option<usize> {
+        self.contains(coordinate)
+            .then(|| coordinate.line as usize * self.width + coordinate.column as usize)
+    }
+
+    pub fn contains(&self, coordinate: Vec2) -> bool {
+        (0..self.height as i64).contains(&coordinate.line)
+            && (0..self.width as i64).contains(&coordinate.column)
+    }
+
+    pub fn get(&self, coordinate: Vec2) -> Option<&T> {
+        self.offset(coordinate).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coordinate: Vec2) -> Option<&mut T> {
+        self.offset(coordinate).map(|i| &mut self.cells[i])
+    }
+
+    /// Every coordinate of the grid, line by line.
+    pub fn coordinates(&self) -> impl Iterator<Item = Vec2> {
+        let width = self.width as i64;
+        (0..self.height as i64)
+            .flat_map(move |line| (0..width).map(move |column| Vec2::new(line, column)))
+    }
+
+    /// Every cell with its coordinate, line by line.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &T)> {
+        self.coordinates().zip(self.cells.iter())
+    }
+
+    /// Orthogonal neighbors of `coordinate` that are inside the grid.
+    pub fn neighbors4(&self, coordinate: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .map(move |direction| coordinate + direction.offset())
+            .filter(|c| self.contains(*c))
+    }
+
+    /// Orthogonal and diagonal neighbors of `coordinate` that are inside the grid.
+    pub fn neighbors8(&self, coordinate: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| coordinate + direction.offset())
+            .filter(|c| self.contains(*c))
+    }
+
+    /// Coordinate of the first cell, line by line, matching `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Vec2> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(c, _)| c)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Vec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Vec2) -> &T {
+        self.get(coordinate)
+            .unwrap_or_else(|| panic!("{:?} is out of the grid", coordinate))
+    }
+}
+
+impl<T> IndexMut<Vec2> for Grid<T> {
+    fn index_mut(&mut self, coordinate: Vec2) -> &mut T {
+        self.get_mut(coordinate)
+            .unwrap_or_else(|| panic!("{:?} is out of the grid", coordinate))
+    }
+}
+
+/// One line of text per line of the grid, each cell rendered with its own `Display`.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.cells.chunks(self.width.max(1)) {
+            for cell in line {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
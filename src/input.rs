@@ -0,0 +1,140 @@
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::{Mode, Puzzle};
+
+/// Environment variable pointing to a directory holding one directory per year, each with
+/// the `input-dayN.txt` files of the year.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+pub const DEFAULT_INPUT_DIR: &str = "src/inputs";
+
+pub const EXAMPLE_DIR: &str = "src/examples";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        puzzle: Puzzle,
+        path: PathBuf,
+    },
+    Io {
+        source: InputSource,
+        error: io::Error,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { puzzle, path } => {
+                write!(f, "input for {} not found at {}", puzzle, path.display())
+            }
+            InputError::Io { source, error } => write!(f, "cannot read {}: {}", source, error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { error, .. } => Some(error),
+        }
+    }
+}
+
+pub fn input_file_name(day: u8) -> String {
+    format!("input-day{}.txt", day)
+}
+
+pub fn example_file_name(day: u8) -> String {
+    format!("example-day{}.txt", day)
+}
+
+/// The example of `puzzle` in [`EXAMPLE_DIR`], relative to the root of the repository.
+pub fn example_path(puzzle: Puzzle) -> PathBuf {
+    Path::new(EXAMPLE_DIR)
+        .join(puzzle.year.to_string())
+        .join(example_file_name(puzzle.day))
+}
+
+/// The directory holding the inputs of every year: [`INPUT_DIR_VAR`] when set, otherwise
+/// [`DEFAULT_INPUT_DIR`].
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// The input of `puzzle` in `dir`, a directory like [`input_dir`] with one directory per
+/// year.
+pub fn input_path(dir: &Path, puzzle: Puzzle) -> PathBuf {
+    dir.join(puzzle.year.to_string())
+        .join(input_file_name(puzzle.day))
+}
+
+/// Picks where the input of `puzzle` comes from, in order: the explicit `--input` path
+/// (`-` meaning stdin, a directory meaning its `YYYY/input-dayN.txt`), the year's directory in
+/// the [`INPUT_DIR_VAR`] directory, and finally the one in [`DEFAULT_INPUT_DIR`]. In
+/// example mode the last two are replaced by the example committed in [`EXAMPLE_DIR`].
+pub fn resolve(puzzle: Puzzle, mode: Mode, explicit: Option<&Path>) -> InputSource {
+    match explicit {
+        Some(path) if path == Path::new("-") => InputSource::Stdin,
+        Some(path) if path.is_dir() => InputSource::File(input_path(path, puzzle)),
+        Some(path) => InputSource::File(path.to_path_buf()),
+        None if mode == Mode::Example => InputSource::File(example_path(puzzle)),
+        None => InputSource::File(input_path(&input_dir(), puzzle)),
+    }
+}
+
+/// Opens the input of `puzzle` to read it little by little.
+pub fn open(puzzle: Puzzle, source: &InputSource) -> Result<Box<dyn BufRead>, InputError> {
+    match source {
+        InputSource::File(path) => match File::open(path) {
+            Ok(file) => Ok(Box::new(BufReader::with_capacity(1 << 16, file))),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Err(InputError::NotFound {
+                puzzle,
+                path: path.clone(),
+            }),
+            Err(error) => Err(InputError::Io {
+                source: source.clone(),
+                error,
+            }),
+        },
+        InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+    }
+}
+
+/// Reads what is left of an input opened with [`open`].
+pub fn read_to_end(source: &InputSource, mut reader: impl Read) -> Result<String, InputError> {
+    let mut content = String::new();
+    reader
+        .read_to_string(&mut content)
+        .map(|_| content)
+        .map_err(|error| InputError::Io {
+            source: source.clone(),
+            error,
+        })
+}
+
+pub fn read(puzzle: Puzzle, source: &InputSource) -> Result<String, InputError> {
+    read_to_end(source, open(puzzle, source)?)
+}
@@ -0,0 +1,83 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod explain;
+pub mod export;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod params;
+pub mod parse;
+pub mod reference;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod visualize;
+pub mod y2024;
+
+pub use params::{Metric, Mode, Pairs, Params, Sort, Trend};
+pub use parse::{ParseError, ReadError};
+pub use solution::{Answer, Part, Solution, Solver};
+
+/// Days of the calendar of every year.
+pub const DAYS: RangeInclusive<u8> = 1..=25;
+
+/// The first Advent of Code, telling years from days on the command line.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Years with a module of their own, `yYYYY`, in order.
+pub const YEARS: &[u16] = &[2024];
+
+/// One puzzle of the calendar: a day of a year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Puzzle {
+    pub fn new(year: u16, day: u8) -> Self {
+        Puzzle { year, day }
+    }
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+/// The year of days given without one.
+pub fn latest_year() -> u16 {
+    *YEARS.last().expect("at least one year")
+}
+
+pub fn get_solver(puzzle: Puzzle, params: &Params) -> Option<Box<dyn Solver>> {
+    match puzzle.year {
+        2024 => y2024::get_solver(puzzle.day, params),
+        _ => None,
+    }
+}
+
+/// Days of `year` that have a solver, in order.
+pub fn days(year: u16) -> Vec<u8> {
+    let params = Params::default();
+    DAYS.filter(|&day| get_solver(Puzzle::new(year, day), &params).is_some())
+        .collect()
+}
+
+/// Every puzzle with a solver, year after year.
+pub fn puzzles() -> Vec<Puzzle> {
+    YEARS
+        .iter()
+        .flat_map(|&year| {
+            days(year)
+                .into_iter()
+                .map(move |day| Puzzle::new(year, day))
+        })
+        .collect()
+}
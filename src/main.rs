@@ -1,5 +1,1020 @@
-fn main() {
+use std::io::{self, IsTerminal};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc::answers::{self, Answers, Verdict};
+use aoc::bench::{self, DayBench, Stats};
+use aoc::client::{Client, ClientConfig, Feedback, Fetched, Submission};
+use aoc::explain;
+use aoc::export::{self, ExportError, ImageFormat};
+use aoc::generate;
+use aoc::reference;
+use aoc::runner::{self, DayResult, RunError};
+use aoc::scaffold;
+use aoc::visualize::{self, Playback};
+use aoc::y2024::day2::Policy;
+use aoc::{
+    latest_year, Answer, Metric, Mode, Pairs, Params, Part, Puzzle, Sort, Trend, DAYS, FIRST_YEAR,
+    YEARS,
+};
+use clap::error::ErrorKind;
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers for one or more puzzles
+    Run {
+        #[command(flatten)]
+        run: RunArgs,
+        /// Output format of the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Run the days concurrently, and their inner loops on every core, then print a
+        /// summary of the answers and timings
+        #[arg(long)]
+        parallel: bool,
+        /// Compare the answers with the naive reference solvers of 2024 days 11, 13, 17 and
+        /// 20, meant for small inputs
+        #[arg(long, conflicts_with = "format")]
+        cross_check: bool,
+        /// Play the grid of 2024 days 6, 12, 14, 15, 16, 18 and 20 frame by frame, in
+        /// colors in a terminal, instead of printing the answers
+        #[arg(long, conflicts_with_all = ["format", "parallel", "cross_check"])]
+        visualize: bool,
+        /// Milliseconds between two frames of `--visualize`
+        #[arg(long, default_value_t = 100, requires = "visualize")]
+        delay: u64,
+        /// Wait for Enter between two frames of `--visualize`: a number then Enter skips
+        /// that many frames, `q` stops
+        #[arg(long, requires = "visualize")]
+        step: bool,
+        /// Also write the last frame of the visualization of the day to an image, PPM, PNG
+        /// or SVG depending on its extension
+        #[arg(long, conflicts_with = "cross_check")]
+        export: Option<PathBuf>,
+        /// Explain how the answers come about before printing them: report by report for
+        /// 2024 day 2, instruction by instruction for 2024 day 3
+        #[arg(
+            long,
+            conflicts_with_all = ["format", "parallel", "cross_check", "visualize", "export"]
+        )]
+        explain: bool,
+    },
+    /// Run the solvers and compare their answers with the stored ones
+    Verify {
+        #[command(flatten)]
+        run: RunArgs,
+        /// Answers file, `answers.toml` (or the answers of the examples with `--example`)
+        /// when omitted
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Time parsing and solving of each day over repeated runs
+    Bench {
+        #[command(flatten)]
+        run: RunArgs,
+        /// Number of runs of each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+    },
+    /// Print a random input of a day, for stress and scaling tests
+    Generate {
+        /// Day to generate an input for, of the latest year unless a year comes first
+        #[arg(
+            value_name = "[YEAR] DAY",
+            value_parser = parse_selection,
+            num_args = 1..=2,
+            required = true
+        )]
+        puzzle: Vec<Selection>,
+        /// Lines, side of the map, bits of the adder... depending on the day
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// Seed of the random generator, the same seed giving the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+    /// Download the puzzle inputs missing from the input directory, with the session
+    /// token in `AOC_SESSION`
+    Fetch {
+        /// Puzzles to download, picked as for `run`
+        #[arg(value_name = "PUZZLES", value_parser = parse_selection)]
+        puzzles: Vec<Selection>,
+        #[command(flatten)]
+        client: ClientArgs,
+    },
+    /// Solve a part on the puzzle input and send the answer, unless an earlier answer
+    /// already tells what the site would say
+    Submit {
+        /// Day to answer, of the latest year unless a year comes first
+        #[arg(
+            value_name = "[YEAR] DAY",
+            value_parser = parse_selection,
+            num_args = 1..=2,
+            required = true
+        )]
+        puzzle: Vec<Selection>,
+        /// Part to answer (1 or 2)
+        #[arg(long, value_parser = parse_part)]
+        part: Part,
+        /// Answer to send instead of the one of the solver
+        #[arg(long)]
+        answer: Option<String>,
+        #[command(flatten)]
+        client: ClientArgs,
+    },
+    /// Start a day: its solver registered with the runner, an empty example and a test,
+    /// never overwriting anything
+    New {
+        /// Day to start, of the latest year unless a year comes first
+        #[arg(
+            value_name = "[YEAR] DAY",
+            value_parser = parse_selection,
+            num_args = 1..=2,
+            required = true
+        )]
+        puzzle: Vec<Selection>,
+    },
+}
+
+#[derive(Args)]
+struct ClientArgs {
+    /// Site to talk to, `AOC_BASE_URL` or adventofcode.com when omitted
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+impl ClientArgs {
+    /// A client with the session token of `AOC_SESSION`.
+    fn client(self) -> Result<Client, String> {
+        let config = ClientConfig::from_env().map_err(|e| e.to_string())?;
+        Ok(Client::new(match self.base_url {
+            Some(base_url) => ClientConfig::new(&base_url, &config.session),
+            None => config,
+        }))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// One block of answers per day
+    Text,
+    /// A JSON array with one record per solved part
+    Json,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Only run the given part (1 or 2)
+    #[arg(long, value_parser = parse_part)]
+    part: Option<Part>,
+    /// Input file, directory of `YYYY/input-dayN.txt` files, or `-` for stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+    #[command(flatten)]
+    params: ParamArgs,
+    /// Puzzles to run: a year then its days (`2024 16`, `2024 1..=25`, `2023 2024 3..7`).
+    /// Days without a year are of the latest year, a year without days stands for all of
+    /// its days, and every puzzle of every year runs when omitted
+    #[arg(value_name = "PUZZLES", value_parser = parse_selection)]
+    puzzles: Vec<Selection>,
+}
+
+/// A year or days on the command line, the days being of the last year before them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Selection {
+    Year(u16),
+    Days(RangeInclusive<u8>),
+}
+
+impl RunArgs {
+    fn selected_puzzles(&self) -> Result<Vec<Puzzle>, String> {
+        select(&self.puzzles)
+    }
+
+    /// Whether the puzzles were picked by year only, e.g. every puzzle when none is given.
+    fn whole_years(&self) -> bool {
+        !self
+            .puzzles
+            .iter()
+            .any(|selection| matches!(selection, Selection::Days(_)))
+    }
+
+    /// Runs every selected puzzle with `run`, handing each result to `report` in order of
+    /// the puzzles. With `parallel`, the puzzles run concurrently and are reported once
+    /// all of them are done.
+    fn run_each<T: Send>(
+        self,
+        parallel: bool,
+        run: impl Fn(Puzzle, Option<Part>, &Params, Option<&Path>) -> Result<T, RunError> + Sync,
+        mut report: impl FnMut(Puzzle, Result<T, RunError>),
+    ) -> ExitCode {
+        let puzzles = match self.selected_puzzles() {
+            Ok(puzzles) => puzzles,
+            Err(message) => {
+                eprintln!("error: {}", message);
+                return ExitCode::FAILURE;
+            }
+        };
+        let params = Params::from(self.params);
+        if puzzles.len() > 1 && self.input.as_deref() == Some(Path::new("-")) {
+            eprintln!("error: stdin can only be used as input of a single day");
+            return ExitCode::FAILURE;
+        }
+
+        let input = self.input.as_deref();
+        if parallel {
+            let results: Vec<_> = puzzles
+                .par_iter()
+                .map(|&puzzle| (puzzle, run(puzzle, self.part, &params, input)))
+                .collect();
+            for (puzzle, result) in results {
+                report(puzzle, result);
+            }
+        } else {
+            for puzzle in puzzles {
+                report(puzzle, run(puzzle, self.part, &params, input));
+            }
+        }
+        ExitCode::SUCCESS
+    }
+}
+
+#[derive(Args, Clone)]
+struct ParamArgs {
+    /// Run on the published examples, with the parameters of the examples
+    #[arg(long, conflicts_with = "real")]
+    example: bool,
+    /// Run on the puzzle inputs (default)
+    #[arg(long)]
+    real: bool,
+    /// Grid width (days 14 and 18), 11 or 101 for day 14 and inferred from the input for
+    /// day 18 when omitted
+    #[arg(long, value_parser = clap::value_parser!(i64).range(1..))]
+    width: Option<i64>,
+    /// Grid height (days 14 and 18), 7 or 103 for day 14 and inferred from the input for
+    /// day 18 when omitted
+    #[arg(long, value_parser = clap::value_parser!(i64).range(1..))]
+    height: Option<i64>,
+    /// Number of fallen bytes before the first path search (day 18)
+    #[arg(long)]
+    bytes: Option<usize>,
+    /// Minimum picoseconds a cheat has to save (day 20)
+    #[arg(long)]
+    save: Option<u32>,
+    /// Number of pairs of gates with swapped outputs (day 24)
+    #[arg(long)]
+    swaps: Option<usize>,
+    /// Number of blinks of part 2 (day 11), 75 when omitted
+    #[arg(long)]
+    blinks: Option<usize>,
+    /// How day 1 sorts its lists: `heap`, or `radix` reading the input line by line for
+    /// very large inputs
+    #[arg(long, value_parser = parse_sort)]
+    sort: Option<Sort>,
+    /// Columns day 1 compares: `pairwise` (every pair) or `first` (each with the first)
+    #[arg(long, value_parser = parse_pairs)]
+    pairs: Option<Pairs>,
+    /// Distance of day 1 between two columns: `absolute`, `squared` or `max`
+    #[arg(long, value_parser = parse_metric)]
+    metric: Option<Metric>,
+    /// Smallest difference between adjacent levels of a safe report (day 2), 1 when omitted
+    #[arg(long)]
+    min_gap: Option<u32>,
+    /// Largest difference between adjacent levels of a safe report (day 2), 3 when omitted
+    #[arg(long)]
+    max_gap: Option<u32>,
+    /// Way the levels of a safe report go (day 2): `increasing`, `decreasing` or `either`
+    #[arg(long, value_parser = parse_trend)]
+    trend: Option<Trend>,
+    /// Levels the Problem Dampener may remove from a report (day 2), 1 when omitted
+    #[arg(long)]
+    removals: Option<usize>,
+}
+
+impl ParamArgs {
+    /// Fails on parameters that contradict each other, as the parser does on a single
+    /// invalid value.
+    fn check(&self) -> Result<(), clap::Error> {
+        Policy::new(&Params::from(self.clone())).map_err(|gaps| {
+            Cli::command().error(
+                ErrorKind::ArgumentConflict,
+                format!(
+                    "--min-gap {} is above --max-gap {}",
+                    gaps.min_gap, gaps.max_gap
+                ),
+            )
+        })?;
+        Ok(())
+    }
+}
+
+impl From<ParamArgs> for Params {
+    fn from(args: ParamArgs) -> Self {
+        Params {
+            mode: if args.example {
+                Mode::Example
+            } else {
+                Mode::Real
+            },
+            width: args.width,
+            height: args.height,
+            bytes: args.bytes,
+            save: args.save,
+            swaps: args.swaps,
+            blinks: args.blinks,
+            sort: args.sort,
+            pairs: args.pairs,
+            metric: args.metric,
+            min_gap: args.min_gap,
+            max_gap: args.max_gap,
+            trend: args.trend,
+            removals: args.removals,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let params = match &cli.command {
+        Command::Run { run, .. } | Command::Verify { run, .. } | Command::Bench { run, .. } => {
+            Some(&run.params)
+        }
+        Command::Generate { params, .. } => Some(params),
+        _ => None,
+    };
+    if let Some(Err(error)) = params.map(ParamArgs::check) {
+        error.exit();
+    }
+
+    // The inner loops of the days share the global pool: a single thread keeps them
+    // sequential, so that benchmarks and plain runs measure one core
+    if !matches!(cli.command, Command::Run { parallel: true, .. }) {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build_global()
+            .expect("the global pool is only configured here");
+    }
+
+    match cli.command {
+        Command::Run {
+            run,
+            parallel,
+            cross_check: true,
+            ..
+        } => cross_check(run, parallel),
+        Command::Run {
+            run, explain: true, ..
+        } => explain(run),
+        Command::Run {
+            run,
+            format,
+            parallel,
+            visualize: show,
+            delay,
+            step,
+            export,
+            ..
+        } => {
+            // Checked before running, which can take a while
+            let export = match export.map(|path| export_target(&run, path)).transpose() {
+                Ok(export) => export,
+                Err(message) => {
+                    eprintln!("error: {}", message);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let params = Params::from(run.params.clone());
+            let input = run.input.clone();
+
+            let status = if show {
+                visualize(
+                    run,
+                    Playback {
+                        delay: Duration::from_millis(delay),
+                        step,
+                        ansi: io::stdout().is_terminal(),
+                    },
+                )
+            } else {
+                run_days(run, format, parallel)
+            };
+            match export {
+                Some((puzzle, path)) => {
+                    match export::export(puzzle, &params, input.as_deref(), &path) {
+                        Ok(_) => status,
+                        Err(e) => {
+                            eprintln!("error: {}", e);
+                            ExitCode::FAILURE
+                        }
+                    }
+                }
+                None => status,
+            }
+        }
+        Command::Verify { run, answers } => verify(run, answers),
+        Command::Bench { run, runs } => bench(run, runs as usize),
+        Command::Generate {
+            puzzle,
+            size,
+            seed,
+            params,
+        } => {
+            let generated = single_puzzle(&puzzle).and_then(|puzzle| {
+                generate::generate(puzzle, size, seed, &Params::from(params))
+                    .ok_or_else(|| format!("{} has no input generator", puzzle))
+            });
+            match generated {
+                Ok(input) => {
+                    // Without a final newline, like the puzzle inputs
+                    print!("{}", input);
+                    ExitCode::SUCCESS
+                }
+                Err(message) => {
+                    eprintln!("error: {}", message);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Fetch { puzzles, client } => fetch(&puzzles, client),
+        Command::Submit {
+            puzzle,
+            part,
+            answer,
+            client,
+        } => match single_puzzle(&puzzle) {
+            Ok(puzzle) => submit(puzzle, part, answer, client),
+            Err(message) => {
+                eprintln!("error: {}", message);
+                ExitCode::FAILURE
+            }
+        },
+        Command::New { puzzle } => {
+            let created = single_puzzle(&puzzle).and_then(|puzzle| {
+                scaffold::new_day(Path::new("."), puzzle).map_err(|e| e.to_string())
+            });
+            match created {
+                Ok(paths) => {
+                    for path in paths {
+                        println!("{}", path.display());
+                    }
+                    ExitCode::SUCCESS
+                }
+                Err(message) => {
+                    eprintln!("error: {}", message);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    }
+}
+
+fn run_days(run: RunArgs, format: Format, parallel: bool) -> ExitCode {
+    let mut failed = false;
+    let mut records = Vec::new();
+    let mut summary = Vec::new();
+    let start = Instant::now();
+    let status = run.run_each(parallel, runner::run_day, |puzzle, result| match result {
+        Ok(result) if format == Format::Json => records.extend(result.records()),
+        Ok(result) if parallel => summary.push(result),
+        Ok(result) => {
+            println!("--- {} Day {} ---", puzzle.year, puzzle.day);
+            for p in result.parts {
+                println!("Part {}: {}", p.part, p.answer);
+            }
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            failed = true;
+        }
+    });
+
+    if format == Format::Json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&records).expect("records are plain data")
+        );
+    } else if parallel {
+        print_summary(&summary, start.elapsed());
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        status
+    }
+}
+
+/// One line per part with its answer and time, then the total time the days took
+/// compared to the time it took to run them all.
+fn print_summary(results: &[DayResult], elapsed: Duration) {
+    println!(
+        "{:>4}  {:>3}  {:>5}  {:<20}  {:>10}",
+        "year", "day", "part", "answer", "time"
+    );
+    let mut total = Duration::ZERO;
+    for result in results {
+        println!(
+            "{:>4}  {:>3}  {:>5}  {:<20}  {:>10.2?}",
+            result.puzzle.year, result.puzzle.day, "parse", "", result.parse_duration
+        );
+        total += result.parse_duration;
+        for p in &result.parts {
+            println!(
+                "{:>4}  {:>3}  {:>5}  {:<20}  {:>10.2?}",
+                result.puzzle.year,
+                result.puzzle.day,
+                p.part,
+                p.answer.to_string(),
+                p.duration
+            );
+            total += p.duration;
+        }
+    }
+
+    let threads = rayon::current_num_threads();
+    println!(
+        "\n{} puzzles in {:.2?} on {} thread{}, {:.2?} of parsing and solving",
+        results.len(),
+        elapsed,
+        threads,
+        if threads == 1 { "" } else { "s" },
+        total
+    );
+}
+
+fn cross_check(run: RunArgs, parallel: bool) -> ExitCode {
+    let (mut agreements, mut disagreements, mut unchecked, mut errors) = (0, 0, 0, 0);
     println!(
-        "Welcome to the advent of code 2024! To run a puzzle, use `cargo run --bin <puzzle-name>`."
+        "{:>4}  {:>3}  {:>4}  {:<9}  {:<20}  reference",
+        "year", "day", "part", "status", "answer"
     );
+    let status = run.run_each(
+        parallel,
+        reference::cross_check,
+        |puzzle, result| match result {
+            Ok(checks) => {
+                for check in checks.into_iter().flatten() {
+                    let status = match check.agrees() {
+                        Some(true) => {
+                            agreements += 1;
+                            "ok"
+                        }
+                        Some(false) => {
+                            disagreements += 1;
+                            "DISAGREES"
+                        }
+                        None => {
+                            unchecked += 1;
+                            "unchecked"
+                        }
+                    };
+                    let row = format!(
+                        "{:>4}  {:>3}  {:>4}  {:<9}  {:<20}  {}",
+                        puzzle.year,
+                        puzzle.day,
+                        check.part,
+                        status,
+                        check.answer.to_string(),
+                        check.reference
+                    );
+                    println!("{}", row.trim_end());
+                }
+            }
+            Err(e) => {
+                println!(
+                    "{:>4}  {:>3}  {:>4}  {:<9}  {}",
+                    puzzle.year, puzzle.day, "-", "ERROR", e
+                );
+                errors += 1;
+            }
+        },
+    );
+
+    println!(
+        "\n{} agree, {} disagree, {} out of reach of the reference, {} failed to run",
+        agreements, disagreements, unchecked, errors
+    );
+    if disagreements > 0 || errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        status
+    }
+}
+
+/// The puzzle whose image `--export` writes to `path`, or why it cannot.
+fn export_target(run: &RunArgs, path: PathBuf) -> Result<(Puzzle, PathBuf), String> {
+    let puzzle = match run.selected_puzzles()?[..] {
+        [puzzle] => puzzle,
+        _ => return Err("--export writes the image of a single day".to_string()),
+    };
+    if run.input.as_deref() == Some(Path::new("-")) {
+        return Err("--export reads the input again, which stdin cannot do".to_string());
+    }
+    if ImageFormat::from_path(&path).is_none() {
+        return Err(ExportError::UnknownFormat { path }.to_string());
+    }
+    if visualize::get_visualizer(puzzle, &Params::from(run.params.clone())).is_none() {
+        return Err(format!("{} has no image to export", puzzle));
+    }
+    Ok((puzzle, path))
+}
+
+fn explain(run: RunArgs) -> ExitCode {
+    // Without days, every day that has an explanation
+    let all_days = run.whole_years();
+    let mut failed = false;
+    let status = run.run_each(false, explain::explain, |puzzle, result| match result {
+        Ok(Some(explanation)) => {
+            println!("--- {} Day {} ---", puzzle.year, puzzle.day);
+            for line in explanation.lines {
+                println!("{}", line);
+            }
+            for (part, answer) in explanation.answers {
+                println!("Part {}: {}", part, answer);
+            }
+        }
+        Ok(None) if all_days => {}
+        Ok(None) => {
+            eprintln!("error: {} has no explanation", puzzle);
+            failed = true;
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            failed = true;
+        }
+    });
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        status
+    }
+}
+
+fn visualize(run: RunArgs, playback: Playback) -> ExitCode {
+    if playback.step && run.input.as_deref() == Some(Path::new("-")) {
+        eprintln!("error: --step reads its commands from stdin, which cannot be the input too");
+        return ExitCode::FAILURE;
+    }
+    // Without days, every day that has a visualization
+    let all_days = run.whole_years();
+    let mut failed = false;
+    let status = run.run_each(
+        false,
+        |puzzle, _, params, input| visualize::play(puzzle, params, input, &playback),
+        |puzzle, result| match result {
+            Ok(true) => {}
+            Ok(false) if all_days => {}
+            Ok(false) => {
+                eprintln!("error: {} has no visualization", puzzle);
+                failed = true;
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                failed = true;
+            }
+        },
+    );
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        status
+    }
+}
+
+fn verify(run: RunArgs, answers: Option<PathBuf>) -> ExitCode {
+    let mode = Params::from(run.params.clone()).mode;
+    let path = answers.unwrap_or_else(|| answers::default_path(mode).to_path_buf());
+    let answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut matches, mut mismatches, mut missing, mut errors) = (0, 0, 0, 0);
+    println!(
+        "{:>4}  {:>3}  {:>4}  {:<8}  {:<20}  expected",
+        "year", "day", "part", "status", "answer"
+    );
+    let status = run.run_each(false, runner::run_day, |puzzle, result| match result {
+        Ok(result) => {
+            for p in result.parts {
+                let verdict = answers.check(puzzle, p.part, &p.answer);
+                let (status, expected) = match &verdict {
+                    // Nothing to lock in yet
+                    Verdict::Missing if p.answer == Answer::Unsolved => continue,
+                    Verdict::Match => ("ok", String::new()),
+                    Verdict::Mismatch(expected) => ("MISMATCH", expected.to_string()),
+                    Verdict::Missing => ("missing", String::new()),
+                };
+                match verdict {
+                    Verdict::Match => matches += 1,
+                    Verdict::Mismatch(_) => mismatches += 1,
+                    Verdict::Missing => missing += 1,
+                }
+                let row = format!(
+                    "{:>4}  {:>3}  {:>4}  {:<8}  {:<20}  {}",
+                    puzzle.year,
+                    puzzle.day,
+                    p.part,
+                    status,
+                    p.answer.to_string(),
+                    expected
+                );
+                println!("{}", row.trim_end());
+            }
+        }
+        Err(e) => {
+            println!(
+                "{:>4}  {:>3}  {:>4}  {:<8}  {}",
+                puzzle.year, puzzle.day, "-", "ERROR", e
+            );
+            errors += 1;
+        }
+    });
+
+    println!(
+        "\n{} ok, {} mismatched, {} missing, {} failed to run",
+        matches, mismatches, missing, errors
+    );
+    if mismatches > 0 || errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        status
+    }
+}
+
+fn bench(run: RunArgs, runs: usize) -> ExitCode {
+    let mut failed = false;
+    let mut total = Duration::ZERO;
+    println!(
+        "{:>4}  {:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "year", "day", "step", "min", "median", "max"
+    );
+    let row = |puzzle: Puzzle, step: &str, stats: &Stats| {
+        println!(
+            "{:>4}  {:>3}  {:<6}  {:>10.2?}  {:>10.2?}  {:>10.2?}",
+            puzzle.year, puzzle.day, step, stats.min, stats.median, stats.max
+        );
+    };
+
+    let status = run.run_each(
+        false,
+        |puzzle, part, params, input| bench::bench_day(puzzle, part, params, input, runs),
+        |puzzle, result: Result<DayBench, RunError>| match result {
+            Ok(result) => {
+                row(puzzle, "parse", &result.parse);
+                for (part, stats) in &result.parts {
+                    row(puzzle, &format!("part {}", part), stats);
+                }
+                total += result.median_total();
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                failed = true;
+            }
+        },
+    );
+
+    println!("\ntotal of the medians over {} runs: {:.2?}", runs, total);
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        status
+    }
+}
+
+fn fetch(selections: &[Selection], client: ClientArgs) -> ExitCode {
+    let (puzzles, mut client) = match select(selections).and_then(|p| Ok((p, client.client()?))) {
+        Ok(selected) => selected,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failed = false;
+    for puzzle in puzzles {
+        match client.fetch_input(puzzle) {
+            Ok(Fetched::Downloaded(path)) => {
+                println!("{}: downloaded to {}", puzzle, path.display())
+            }
+            Ok(Fetched::Cached(path)) => println!("{}: cached at {}", puzzle, path.display()),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn submit(puzzle: Puzzle, part: Part, answer: Option<String>, client: ClientArgs) -> ExitCode {
+    let submitted = client.client().and_then(|mut client| {
+        let answer = match answer {
+            Some(answer) => answer
+                .parse()
+                .map(Answer::Number)
+                .unwrap_or(Answer::Text(answer)),
+            None => {
+                client.fetch_input(puzzle).map_err(|e| e.to_string())?;
+                let result = runner::run_day(puzzle, Some(part), &Params::default(), None)
+                    .map_err(|e| e.to_string())?;
+                result.parts[0].answer.clone()
+            }
+        };
+        if answer == Answer::Unsolved {
+            return Err(format!("{} part {} has no answer to send", puzzle, part));
+        }
+        let submission = client
+            .submit(puzzle, part, &answer)
+            .map_err(|e| e.to_string())?;
+        Ok((answer, submission))
+    });
+
+    let prefix = format!("{} part {}", puzzle, part);
+    match submitted {
+        Ok((answer, Submission::Sent(feedback))) => {
+            println!("{}: {} is {}", prefix, answer, feedback);
+            if feedback == Feedback::Correct {
+                return ExitCode::SUCCESS;
+            }
+        }
+        Ok((answer, Submission::Known(feedback))) => {
+            println!(
+                "{}: {} is {}, going by the answers sent before (not sent)",
+                prefix, answer, feedback
+            );
+            if feedback == Feedback::Correct {
+                return ExitCode::SUCCESS;
+            }
+        }
+        Ok((_, Submission::TooSoon(wait))) => println!(
+            "{}: the site asks to wait {}s before the next answer (not counted)",
+            prefix,
+            wait.as_secs()
+        ),
+        Ok((_, Submission::WrongLevel)) => println!(
+            "{}: the site expects no answer to this part, solved already or still locked",
+            prefix
+        ),
+        Err(message) => eprintln!("error: {}", message),
+    }
+    ExitCode::FAILURE
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("part must be 1 or 2, got `{}`", value)),
+    }
+}
+
+fn parse_sort(value: &str) -> Result<Sort, String> {
+    match value {
+        "heap" => Ok(Sort::Heap),
+        "radix" => Ok(Sort::Radix),
+        _ => Err(format!("sort must be heap or radix, got `{}`", value)),
+    }
+}
+
+fn parse_pairs(value: &str) -> Result<Pairs, String> {
+    match value {
+        "pairwise" => Ok(Pairs::Pairwise),
+        "first" => Ok(Pairs::First),
+        _ => Err(format!("pairs must be pairwise or first, got `{}`", value)),
+    }
+}
+
+fn parse_metric(value: &str) -> Result<Metric, String> {
+    match value {
+        "absolute" => Ok(Metric::Absolute),
+        "squared" => Ok(Metric::Squared),
+        "max" => Ok(Metric::Max),
+        _ => Err(format!(
+            "metric must be absolute, squared or max, got `{}`",
+            value
+        )),
+    }
+}
+
+fn parse_trend(value: &str) -> Result<Trend, String> {
+    match value {
+        "increasing" => Ok(Trend::Increasing),
+        "decreasing" => Ok(Trend::Decreasing),
+        "either" => Ok(Trend::Either),
+        _ => Err(format!(
+            "trend must be increasing, decreasing or either, got `{}`",
+            value
+        )),
+    }
+}
+
+/// The puzzles the `selections` of the command line stand for: every puzzle of every year
+/// without any.
+fn select(selections: &[Selection]) -> Result<Vec<Puzzle>, String> {
+    if selections.is_empty() {
+        return Ok(aoc::puzzles());
+    }
+
+    let whole_year = |year: u16| {
+        if !YEARS.contains(&year) {
+            return Err(format!("there are no puzzles of {} yet", year));
+        }
+        Ok(aoc::days(year)
+            .into_iter()
+            .map(move |day| Puzzle::new(year, day)))
+    };
+    let mut puzzles = Vec::new();
+    let mut year = latest_year();
+    // The last year given, as long as no day followed it
+    let mut without_days = None;
+    for selection in selections {
+        match selection {
+            Selection::Year(next) => {
+                if let Some(previous) = without_days.replace(*next) {
+                    puzzles.extend(whole_year(previous)?);
+                }
+                year = *next;
+            }
+            Selection::Days(days) => {
+                without_days = None;
+                puzzles.extend(days.clone().map(|day| Puzzle::new(year, day)));
+            }
+        }
+    }
+    if let Some(year) = without_days {
+        puzzles.extend(whole_year(year)?);
+    }
+    Ok(puzzles)
+}
+
+/// A year from [`FIRST_YEAR`] on, or days as [`parse_days`] reads them.
+fn parse_selection(value: &str) -> Result<Selection, String> {
+    match value.trim().parse::<u16>() {
+        Ok(year) if year >= FIRST_YEAR => Ok(Selection::Year(year)),
+        _ => parse_days(value).map(Selection::Days),
+    }
+}
+
+/// The one day given to `generate` and `new`, with an optional year before it.
+fn single_puzzle(selections: &[Selection]) -> Result<Puzzle, String> {
+    let (year, days) = match selections {
+        [Selection::Days(days)] => (latest_year(), days),
+        [Selection::Year(year), Selection::Days(days)] => (*year, days),
+        _ => return Err("expected a day, or a year then a day".to_string()),
+    };
+    match (days.start(), days.end()) {
+        (start, end) if start == end => Ok(Puzzle::new(year, *start)),
+        _ => Err("expected a single day, not a range".to_string()),
+    }
+}
+
+fn parse_days(value: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse_day = |day: &str| -> Result<u8, String> {
+        day.trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|day| DAYS.contains(day))
+            .ok_or_else(|| format!("`{}` is not a day between 1 and 25", day))
+    };
+
+    let range = if let Some((start, end)) = value.split_once("..=") {
+        parse_day(start)?..=parse_day(end)?
+    } else if let Some((start, end)) = value.split_once("..") {
+        let end = parse_day(end)?;
+        if end == *DAYS.start() {
+            return Err(format!("`{}` is an empty range", value));
+        }
+        parse_day(start)?..=end - 1
+    } else {
+        let day = parse_day(value)?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        return Err(format!("`{}` is an empty range", value));
+    }
+    Ok(range)
 }
@@ -0,0 +1,83 @@
+/// Whether a day runs on the published example or on the personal puzzle input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    Example,
+    #[default]
+    Real,
+}
+
+/// How day 1 of 2024 sorts its lists: popping them from binary heaps, or with a radix sort
+/// of lists read line by line, meant for inputs too large to hold as text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Sort {
+    #[default]
+    Heap,
+    Radix,
+}
+
+/// Which columns of day 1 of 2024 are compared: every pair of them, or each column with the
+/// first one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Pairs {
+    #[default]
+    Pairwise,
+    First,
+}
+
+/// How day 1 of 2024 measures the distance between two sorted columns: the sum of the
+/// absolute or squared differences of their numbers, or the largest difference.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Metric {
+    #[default]
+    Absolute,
+    Squared,
+    Max,
+}
+
+/// The way the levels of a report of day 2 of 2024 have to go to be safe: up, down, or
+/// either as long as all of them go the same way.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+    #[default]
+    Either,
+}
+
+/// Puzzle parameters that are not part of the input itself, such as the size of the
+/// bathroom in day 14. Every `None` falls back to what the input allows to infer or,
+/// failing that, to the value the puzzle uses for the current [`Mode`].
+#[derive(Clone, Debug, Default)]
+pub struct Params {
+    pub mode: Mode,
+    pub width: Option<i64>,
+    pub height: Option<i64>,
+    pub bytes: Option<usize>,
+    pub save: Option<u32>,
+    pub swaps: Option<usize>,
+    pub blinks: Option<usize>,
+    pub sort: Option<Sort>,
+    pub pairs: Option<Pairs>,
+    pub metric: Option<Metric>,
+    pub min_gap: Option<u32>,
+    pub max_gap: Option<u32>,
+    pub trend: Option<Trend>,
+    pub removals: Option<usize>,
+}
+
+impl Params {
+    pub fn with_mode(mode: Mode) -> Self {
+        Params {
+            mode,
+            ..Params::default()
+        }
+    }
+
+    /// Picks `value` when given, otherwise the default of the current mode.
+    pub fn or_default<T>(&self, value: Option<T>, example: T, real: T) -> T {
+        value.unwrap_or(match self.mode {
+            Mode::Example => example,
+            Mode::Real => real,
+        })
+    }
+}
@@ -0,0 +1,202 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+/// A malformed or truncated puzzle input, pointing at the offending text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// Line of the offending text, starting at 1
+    pub line: usize,
+    /// Column of the offending text in characters, starting at 1
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} input, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " (found `{}`)", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// An input that could not be read to the end, or not parsed while reading it.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "{}", e),
+            ReadError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Io(e) => Some(e),
+            ReadError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        ReadError::Parse(e)
+    }
+}
+
+/// The input as every parser expects it, whatever editor saved it: no byte order mark,
+/// `\n` line endings, no trailing whitespace on a line and no blank lines at the end.
+/// Borrowed when the input is already clean.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let clean = input.trim_end().len() == input.len()
+        && input
+            .split('\n')
+            .all(|line| line.trim_end().len() == line.len());
+    if clean {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(lines(input).collect::<Vec<_>>().join("\n"))
+    }
+}
+
+/// Lines of `text` without their trailing whitespace, ignoring blank lines at the end.
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.trim_end().lines().map(str::trim_end)
+}
+
+/// Blocks of lines of `text` separated by one or more blank lines, each without its
+/// final line break, e.g. the rules and the updates of day 5.
+pub fn sections(text: &str) -> impl Iterator<Item = &str> {
+    let mut sections = Vec::new();
+    let mut section: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end();
+        if content.is_empty() {
+            sections.extend(section.take());
+        } else {
+            let start = section.map_or(offset, |(start, _)| start);
+            section = Some((start, offset + content.len()));
+        }
+        offset += line.len();
+    }
+    sections.extend(section);
+    sections
+        .into_iter()
+        .map(move |(start, end)| &text[start..end])
+}
+
+/// The whole input of a day. Parsers keep slicing it with [`sections`], [`lines`] and
+/// regex captures, and hand the failing slice back to build an error at the right position.
+#[derive(Clone, Copy, Debug)]
+pub struct Source<'a> {
+    day: u8,
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(day: u8, input: &'a str) -> Self {
+        Source { day, input }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.input
+    }
+
+    /// Line and column of the start of `fragment`, which must be a slice of the input.
+    /// Text coming from elsewhere is reported at the end of the input.
+    fn locate(&self, fragment: &str) -> (usize, usize) {
+        let start = self.input.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= self.input.len())
+            .unwrap_or(self.input.len());
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    pub fn error(&self, fragment: &str, message: impl Into<String>) -> ParseError {
+        let (line, column) = self.locate(fragment);
+        ParseError {
+            day: self.day,
+            line,
+            column,
+            text: fragment.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error for something expected right after `fragment`, e.g. a truncated line.
+    pub fn missing(&self, after: &str, what: &str) -> ParseError {
+        let end = &after[after.len()..];
+        self.error(end, format!("expected {}", what))
+    }
+
+    /// Error for the character starting at byte `offset` of `line`.
+    pub fn unexpected_char(&self, line: &str, offset: usize, expected: &str) -> ParseError {
+        let character = line[offset..].chars().next().map_or(0, char::len_utf8);
+        self.error(
+            &line[offset..offset + character],
+            format!("expected {}", expected),
+        )
+    }
+
+    pub fn number<T: FromStr>(&self, fragment: &str) -> Result<T, ParseError> {
+        fragment
+            .parse()
+            .map_err(|_| self.error(fragment, "expected a number"))
+    }
+
+    /// Checks that `c` appears exactly once in `text`, e.g. the start tile of a maze.
+    pub fn single(&self, text: &str, c: char, what: &str) -> Result<(), ParseError> {
+        let mut found = text.match_indices(c);
+        match (found.next(), found.next()) {
+            (Some(_), None) => Ok(()),
+            (None, _) => Err(self.missing(text, what)),
+            (Some(_), Some((offset, second))) => Err(self.error(
+                &text[offset..offset + second.len()],
+                format!("expected a single {}", what),
+            )),
+        }
+    }
+
+    /// Next item of `fields`, or an error right after `after` naming the missing `what`.
+    pub fn next<'b>(
+        &self,
+        fields: &mut impl Iterator<Item = &'b str>,
+        after: &str,
+        what: &str,
+    ) -> Result<&'b str, ParseError> {
+        fields.next().ok_or_else(|| self.missing(after, what))
+    }
+}
@@ -0,0 +1,416 @@
+//! Deliberately naive solvers of the 2024 days whose solutions take a shortcut, to check them
+//! against on small inputs: the closed form of day 13, the search over octal digits of
+//! day 17, the Manhattan distance count of day 20 and the count map of day 11. They
+//! favor being obviously right over being fast, and leave a part `Unsolved` when the
+//! input is out of their reach.
+
+use std::collections::VecDeque;
+use std::path::Path;
+
+use crate::geometry::{Direction4, Vec2};
+use crate::grid::Grid;
+use crate::parse::{sections, Source};
+use crate::runner::{self, RunError};
+use crate::y2024::{self, day11, day20};
+use crate::{get_solver, Answer, Params, ParseError, Part, Puzzle, Solution, Solver};
+
+/// Blinks of day 11 when cross-checking without `--blinks`: blinking stone by stone
+/// takes forever long before the 75 blinks of the puzzle.
+pub const CROSS_CHECK_BLINKS: usize = 25;
+
+pub fn get_reference(puzzle: Puzzle, params: &Params) -> Option<Box<dyn Solver>> {
+    match puzzle.year {
+        2024 => y2024::get_reference(puzzle.day, params),
+        _ => None,
+    }
+}
+
+/// The answers of the solver and of the reference to one part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Check {
+    pub part: Part,
+    pub answer: Answer,
+    pub reference: Answer,
+}
+
+impl Check {
+    /// `None` when the part is out of reach of the reference.
+    pub fn agrees(&self) -> Option<bool> {
+        (self.reference != Answer::Unsolved).then(|| self.answer == self.reference)
+    }
+}
+
+/// [`check`] on the input [`runner::load`] picks.
+pub fn cross_check(
+    puzzle: Puzzle,
+    part: Option<Part>,
+    params: &Params,
+    input: Option<&Path>,
+) -> Result<Option<Vec<Check>>, RunError> {
+    let (_, content) = runner::load(puzzle, params, input)?;
+    Ok(check(puzzle, part, params, &content)?)
+}
+
+/// Solves the parts of `puzzle` selected by the `part` filter with both the solver and
+/// its reference. `None` when `puzzle` has no reference.
+pub fn check(
+    puzzle: Puzzle,
+    part: Option<Part>,
+    params: &Params,
+    content: &str,
+) -> Result<Option<Vec<Check>>, ParseError> {
+    let params = Params {
+        blinks: params.blinks.or(Some(CROSS_CHECK_BLINKS)),
+        ..params.clone()
+    };
+    let Some(reference) = get_reference(puzzle, &params) else {
+        return Ok(None);
+    };
+    let solver = get_solver(puzzle, &params).expect("puzzles with a reference are solved");
+    let parsed = solver.parse(content)?;
+    let reference_parsed = reference.parse(content)?;
+
+    let checks = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|p| p.is_selected(part))
+        .map(|p| Check {
+            part: p,
+            answer: solver.solve(parsed.as_ref(), p),
+            reference: reference.solve(reference_parsed.as_ref(), p),
+        })
+        .collect();
+    Ok(Some(checks))
+}
+
+/// Every number of `text`, in order, ignoring any sign.
+fn numbers<'a>(
+    source: &'a Source,
+    text: &'a str,
+) -> impl Iterator<Item = Result<i64, ParseError>> + 'a {
+    text.split(|c: char| !c.is_ascii_digit())
+        .filter(|n| !n.is_empty())
+        .map(|n| source.number(n))
+}
+
+/// Blinks at every stone, one at a time.
+pub struct Day11 {
+    blinks: usize,
+}
+
+impl Day11 {
+    pub fn new(params: &Params) -> Self {
+        Day11 {
+            blinks: params.blinks.unwrap_or(day11::BLINKS),
+        }
+    }
+}
+
+fn blink_at_every_stone(stones: &[u64], blinks: usize) -> usize {
+    let mut stones = stones.to_vec();
+    for _ in 0..blinks {
+        let mut next = Vec::with_capacity(stones.len() * 2);
+        for stone in stones {
+            let digits = if stone == 0 { 1 } else { stone.ilog10() + 1 };
+            if stone == 0 {
+                next.push(1);
+            } else if digits % 2 == 0 {
+                let half = 10u64.pow(digits / 2);
+                next.push(stone / half);
+                next.push(stone % half);
+            } else {
+                next.push(stone * 2024);
+            }
+        }
+        stones = next;
+    }
+    stones.len()
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        input.split_whitespace().map(|n| source.number(n)).collect()
+    }
+
+    fn part1(&self, stones: &Vec<u64>) -> Answer {
+        blink_at_every_stone(stones, 25).into()
+    }
+
+    fn part2(&self, stones: &Vec<u64>) -> Answer {
+        blink_at_every_stone(stones, self.blinks).into()
+    }
+}
+
+/// Tries every number of pushes up to the 100 of part 1. Part 2 bisects the pushes of A
+/// for the one leaving a distance to the prize along B, then checks it with exact
+/// integers, instead of solving the equations.
+pub struct Day13;
+
+/// Pushes of A above which part 2 gives up on buttons going the same way, which leave
+/// nothing to bisect.
+const MAX_COLLINEAR_PUSHES: i128 = 1_000_000;
+
+/// The fewest tokens winning `prize`, `None` when it cannot be won, or `Err` when the
+/// buttons go the same way and the pushes are too many to try one by one.
+fn cheapest_win(a: [i128; 2], b: [i128; 2], prize: [i128; 2]) -> Result<Option<i128>, ()> {
+    let tokens = |pushes_a: i128| {
+        let rest = [prize[0] - pushes_a * a[0], prize[1] - pushes_a * a[1]];
+        let pushes_b = match b {
+            [0, 0] => 0,
+            [0, by] => rest[1] / by,
+            [bx, _] => rest[0] / bx,
+        };
+        let wins = (0..2).all(|i| rest[i] >= 0 && pushes_b * b[i] == rest[i]);
+        wins.then_some(3 * pushes_a + pushes_b)
+    };
+    let max_pushes = (0..2)
+        .filter(|&i| a[i] > 0)
+        .map(|i| prize[i] / a[i])
+        .min()
+        .unwrap_or(0);
+    // Positive, zero or negative as the prize is on one side of the line along B from the
+    // claw after `pushes_a` pushes of A, on it, or on the other side
+    let side = |pushes_a: i128| {
+        ((prize[0] - pushes_a * a[0]) * b[1] - (prize[1] - pushes_a * a[1]) * b[0]).signum()
+    };
+
+    let (mut low, mut high) = (0, max_pushes);
+    if side(low) == side(high) {
+        if side(low) != 0 {
+            return Ok(None);
+        }
+        if max_pushes > MAX_COLLINEAR_PUSHES {
+            return Err(());
+        }
+        return Ok((0..=max_pushes).filter_map(tokens).min());
+    }
+    while high - low > 1 {
+        let middle = (low + high) / 2;
+        if side(middle) == side(low) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    Ok([low, high].into_iter().filter_map(tokens).min())
+}
+
+/// Buttons A and B then the prize, as `[x, y]`.
+type Machine = [[i64; 2]; 3];
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Machine>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Machine>, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        sections(input)
+            .map(|block| {
+                let n = numbers(&source, block).collect::<Result<Vec<i64>, _>>()?;
+                match n[..] {
+                    [ax, ay, bx, by, px, py] => Ok([[ax, ay], [bx, by], [px, py]]),
+                    _ => Err(source.error(block, "expected 6 numbers")),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(&self, machines: &Vec<Machine>) -> Answer {
+        let tokens: i64 = machines
+            .iter()
+            .filter_map(|&[a, b, prize]| {
+                (0..=100)
+                    .flat_map(|pushes_a| (0..=100).map(move |pushes_b| (pushes_a, pushes_b)))
+                    .filter(|(pushes_a, pushes_b)| {
+                        (0..2).all(|i| pushes_a * a[i] + pushes_b * b[i] == prize[i])
+                    })
+                    .map(|(pushes_a, pushes_b)| 3 * pushes_a + pushes_b)
+                    .min()
+            })
+            .sum();
+        (tokens as u64).into()
+    }
+
+    fn part2(&self, machines: &Vec<Machine>) -> Answer {
+        let tokens: Result<Vec<Option<i128>>, ()> = machines
+            .iter()
+            .map(|&[a, b, prize]| {
+                cheapest_win(
+                    a.map(i128::from),
+                    b.map(i128::from),
+                    prize.map(|p| i128::from(p) + 10_000_000_000_000),
+                )
+            })
+            .collect();
+        match tokens {
+            Ok(tokens) => (tokens.into_iter().flatten().sum::<i128>() as u64).into(),
+            Err(()) => Answer::Unsolved,
+        }
+    }
+}
+
+/// Outputs of `program` run from the `registers` A, B and C, by a plain interpreter.
+pub(crate) fn run_program(program: &[u64], registers: [u64; 3]) -> Vec<u64> {
+    let [mut a, mut b, mut c] = registers;
+    let mut output = Vec::new();
+    let mut pointer = 0;
+    while pointer + 1 < program.len() {
+        let operand = program[pointer + 1];
+        let combo = match operand {
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => operand,
+        };
+        match program[pointer] {
+            0 => a >>= combo.min(63),
+            1 => b ^= operand,
+            2 => b = combo % 8,
+            3 if a != 0 => {
+                pointer = operand as usize;
+                continue;
+            }
+            4 => b ^= c,
+            5 => output.push(combo % 8),
+            6 => b = a >> combo.min(63),
+            7 => c = a >> combo.min(63),
+            _ => {}
+        }
+        pointer += 2;
+    }
+    output
+}
+
+/// Runs the program with every value of register A in turn, up to [`Day17::MAX_A`].
+pub struct Day17;
+
+impl Day17 {
+    /// Every value of 8 octal digits, enough for the programs of 4 instructions of
+    /// [`crate::generate`]
+    pub const MAX_A: u64 = 1 << 24;
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    /// Registers then program
+    type Input = ([u64; 3], Vec<u64>);
+
+    fn parse(&self, input: &str) -> Result<([u64; 3], Vec<u64>), ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let n = numbers(&source, input).collect::<Result<Vec<i64>, _>>()?;
+        match n[..] {
+            [a, b, c, ref program @ ..] => Ok((
+                [a, b, c].map(|r| r as u64),
+                program.iter().map(|&p| p as u64).collect(),
+            )),
+            _ => Err(source.missing(input, "three registers")),
+        }
+    }
+
+    fn part1(&self, (registers, program): &([u64; 3], Vec<u64>)) -> Answer {
+        let output: Vec<String> = run_program(program, *registers)
+            .iter()
+            .map(|n| n.to_string())
+            .collect();
+        output.join(",").into()
+    }
+
+    fn part2(&self, (registers, program): &([u64; 3], Vec<u64>)) -> Answer {
+        (0..Self::MAX_A)
+            .find(|&a| run_program(program, [a, registers[1], registers[2]]) == *program)
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+/// Walks every cheat from every tile of the track, through walls, and times the race
+/// with each of them from the distances to the start and to the end.
+pub struct Day20 {
+    picoseconds_to_save: u32,
+}
+
+impl Day20 {
+    pub fn new(params: &Params) -> Self {
+        Day20 {
+            picoseconds_to_save: day20::picoseconds_to_save(params),
+        }
+    }
+
+    fn count_cheats(&self, track: &Grid<char>, cheat_steps: u32) -> u64 {
+        let start = track.find(|&c| c == 'S').expect("checked while parsing");
+        let end = track.find(|&c| c == 'E').expect("checked while parsing");
+        let from_start = distances(track, start, false, u32::MAX);
+        let to_end = distances(track, end, false, u32::MAX);
+        let Some(fastest) = from_start[end] else {
+            return 0;
+        };
+
+        let mut cheats = 0;
+        for (cheat_start, before) in from_start.iter() {
+            let Some(before) = before else { continue };
+            let cheat = distances(track, cheat_start, true, cheat_steps);
+            for (cheat_end, steps) in cheat.iter() {
+                if let (Some(steps), Some(after)) = (steps, to_end[cheat_end]) {
+                    let time = before + steps + after;
+                    if time + self.picoseconds_to_save <= fastest {
+                        cheats += 1;
+                    }
+                }
+            }
+        }
+        cheats
+    }
+}
+
+/// Steps from `from` to every tile of the track within `max` steps, through walls when
+/// `cheating`. Walls themselves are never reached.
+fn distances(track: &Grid<char>, from: Vec2, cheating: bool, max: u32) -> Grid<Option<u32>> {
+    let mut reached = Grid::new(track.width(), track.height(), None);
+    reached[from] = Some(0);
+    let mut queue = VecDeque::from([(from, 0)]);
+    while let Some((tile, steps)) = queue.pop_front() {
+        if steps == max {
+            continue;
+        }
+        for direction in Direction4::ALL {
+            let next = tile + direction.offset();
+            if track.get(next).is_some_and(|&c| cheating || c != '#') && reached[next].is_none() {
+                reached[next] = Some(steps + 1);
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    track.iter().for_each(|(tile, &c)| {
+        if c == '#' {
+            reached[tile] = None;
+        }
+    });
+    reached
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Grid<char>, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let track = Grid::parse(&source, input, Some, "a tile")?;
+        source.single(input, 'S', "start `S`")?;
+        source.single(input, 'E', "end `E`")?;
+        Ok(track)
+    }
+
+    fn part1(&self, track: &Grid<char>) -> Answer {
+        self.count_cheats(track, 2).into()
+    }
+
+    fn part2(&self, track: &Grid<char>) -> Answer {
+        self.count_cheats(track, 20).into()
+    }
+}
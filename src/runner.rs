@@ -0,0 +1,179 @@
+use std::any::Any;
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::export::ExportError;
+use crate::input::{self, InputError, InputSource};
+use crate::{get_solver, Answer, Params, ParseError, Part, Puzzle, ReadError, Solver};
+
+/// The answer computed for one part of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayResult {
+    pub puzzle: Puzzle,
+    pub parse_duration: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+/// One solved part, as emitted by `--format json`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub answer_type: &'static str,
+    pub duration_ns: u64,
+}
+
+impl DayResult {
+    /// Records of the solved parts, leaving out the unsolved ones.
+    pub fn records(&self) -> Vec<Record> {
+        self.parts
+            .iter()
+            .filter(|p| p.answer != Answer::Unsolved)
+            .map(|p| Record {
+                year: self.puzzle.year,
+                day: self.puzzle.day,
+                part: p.part.number(),
+                answer: p.answer.clone(),
+                answer_type: p.answer.kind(),
+                duration_ns: p.duration.as_nanos() as u64,
+            })
+            .collect()
+    }
+}
+
+/// Why a day could not be run at all.
+#[derive(Debug)]
+pub enum RunError {
+    /// The puzzle has no solver, e.g. a day not started yet
+    Unsolved(Puzzle),
+    Input(InputError),
+    Parse(ParseError),
+    Export(ExportError),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Unsolved(puzzle) => write!(f, "{} has no solver", puzzle),
+            RunError::Input(e) => write!(f, "{}", e),
+            RunError::Parse(e) => write!(f, "{}", e),
+            RunError::Export(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for RunError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RunError::Unsolved(_) => None,
+            RunError::Input(e) => Some(e),
+            RunError::Parse(e) => Some(e),
+            RunError::Export(e) => Some(e),
+        }
+    }
+}
+
+impl From<InputError> for RunError {
+    fn from(e: InputError) -> Self {
+        RunError::Input(e)
+    }
+}
+
+impl From<ParseError> for RunError {
+    fn from(e: ParseError) -> Self {
+        RunError::Parse(e)
+    }
+}
+
+impl From<ExportError> for RunError {
+    fn from(e: ExportError) -> Self {
+        RunError::Export(e)
+    }
+}
+
+/// The solver of `puzzle` along with its input, read as described in [`input::resolve`].
+pub fn load(
+    puzzle: Puzzle,
+    params: &Params,
+    input: Option<&Path>,
+) -> Result<(Box<dyn Solver>, String), RunError> {
+    let solver = get_solver(puzzle, params).ok_or(RunError::Unsolved(puzzle))?;
+    let source = input::resolve(puzzle, params.mode, input);
+    let content = input::read(puzzle, &source)?;
+
+    Ok((solver, content))
+}
+
+/// Runs `f` and measures its wall time.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+/// Parses the input of `puzzle` from `source`, while reading it when `solver` can. The
+/// duration then includes the reading.
+fn parse(
+    puzzle: Puzzle,
+    solver: &dyn Solver,
+    source: &InputSource,
+) -> Result<(Box<dyn Any>, Duration), RunError> {
+    let mut reader = input::open(puzzle, source)?;
+    match timed(|| solver.read(&mut reader)) {
+        (Some(Ok(input)), duration) => Ok((input, duration)),
+        (Some(Err(ReadError::Io(error))), _) => Err(RunError::Input(InputError::Io {
+            source: source.clone(),
+            error,
+        })),
+        (Some(Err(ReadError::Parse(e))), _) => Err(e.into()),
+        (None, _) => {
+            let content = input::read_to_end(source, reader)?;
+            let (input, duration) = timed(|| solver.parse(&content));
+            Ok((input?, duration))
+        }
+    }
+}
+
+/// Reads and parses the input of `puzzle` once, then solves the parts selected by the
+/// `part` filter.
+pub fn run_day(
+    puzzle: Puzzle,
+    part: Option<Part>,
+    params: &Params,
+    input: Option<&Path>,
+) -> Result<DayResult, RunError> {
+    let solver = get_solver(puzzle, params).ok_or(RunError::Unsolved(puzzle))?;
+    let source = input::resolve(puzzle, params.mode, input);
+    let (input, parse_duration) = parse(puzzle, solver.as_ref(), &source)?;
+
+    let parts = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|p| p.is_selected(part))
+        .map(|p| {
+            let (answer, duration) = timed(|| solver.solve(input.as_ref(), p));
+            PartResult {
+                part: p,
+                answer,
+                duration,
+            }
+        })
+        .collect();
+
+    Ok(DayResult {
+        puzzle,
+        parse_duration,
+        parts,
+    })
+}
@@ -0,0 +1,446 @@
+//! `aoc new [year] <day>`: the skeleton of a new day, registered with the runner, along
+//! with an empty example and a test waiting for its answers. The first day of a year also
+//! starts the module of the year.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::input;
+use crate::Puzzle;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day has a file already, which is never overwritten
+    Exists {
+        path: PathBuf,
+    },
+    /// The day is declared in a file already, e.g. with a solver in `get_solver`
+    Registered {
+        puzzle: Puzzle,
+        path: PathBuf,
+    },
+    /// A file to update does not have the layout the scaffolding relies on
+    Layout {
+        path: PathBuf,
+        expected: String,
+    },
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists { path } => {
+                write!(
+                    f,
+                    "{} already exists, leaving everything as is",
+                    path.display()
+                )
+            }
+            ScaffoldError::Registered { puzzle, path } => write!(
+                f,
+                "{} is already in {}, leaving everything as is",
+                puzzle,
+                path.display()
+            ),
+            ScaffoldError::Layout { path, expected } => {
+                write!(f, "cannot update {}: expected {}", path.display(), expected)
+            }
+            ScaffoldError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScaffoldError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|error| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+fn write(path: &Path, content: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, content).map_err(|error| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// The solver of a day that parses its lines and solves nothing yet.
+pub fn solver_template(day: u8) -> String {
+    format!(
+        r#"use crate::parse::{{lines, Source}};
+use crate::{{Answer, ParseError, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {{
+        let source = Source::new(Self::DAY, input);
+        lines(input)
+            .map(|line| {{
+                if line.is_empty() {{
+                    Err(source.missing(line, "a line"))
+                }} else {{
+                    Ok(line.to_string())
+                }}
+            }})
+            .collect()
+    }}
+
+    fn part1(&self, _lines: &Vec<String>) -> Answer {{
+        Answer::Unsolved
+    }}
+}}
+"#
+    )
+}
+
+/// The module of a year whose only day is the one of `puzzle`.
+fn year_template(puzzle: Puzzle) -> String {
+    format!(
+        r#"//! The puzzles of {year}.
+
+use crate::{{Params, Solver}};
+
+pub mod day{day};
+
+pub fn get_solver(day: u8, _params: &Params) -> Option<Box<dyn Solver>> {{
+    let solver: Box<dyn Solver> = match day {{
+        {day} => Box::new(day{day}::Day{day}),
+        _ => return None,
+    }};
+    Some(solver)
+}}
+"#,
+        year = puzzle.year,
+        day = puzzle.day
+    )
+}
+
+/// The tests of a year on its examples, without any test yet.
+fn tests_template(year: u16) -> String {
+    format!(
+        r#"//! The {year} puzzles on their examples.
+
+use aoc::{{Answer, Puzzle}};
+
+fn puzzle(day: u8) -> Puzzle {{
+    Puzzle::new({year}, day)
+}}
+
+fn solve(day: u8) -> (Answer, Answer) {{
+    super::solve(puzzle(day))
+}}
+"#
+    )
+}
+
+fn test_template(day: u8) -> String {
+    format!(
+        r#"#[test]
+#[ignore = "waiting for the example and its answers"]
+fn day{day}() {{
+    assert_eq!(solve({day}), (Answer::Unsolved, Answer::Unsolved));
+}}
+"#
+    )
+}
+
+/// Lines of `text` with the offset each starts at.
+fn lines_at(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        Some((start, line))
+    })
+}
+
+/// Where a declaration or an arm goes in a file.
+enum Placement {
+    At(usize),
+    Exists,
+    /// The file does not have the declarations or the function to extend
+    Missing,
+}
+
+/// Where to declare `module` among the modules `family` picks, sorted by name as rustfmt
+/// keeps them.
+fn module_at(text: &str, module: &str, family: impl Fn(&str) -> bool) -> Placement {
+    let modules: Vec<(usize, &str)> = lines_at(text)
+        .filter_map(|(offset, line)| {
+            let declared = line
+                .strip_prefix("pub mod ")
+                .or_else(|| line.strip_prefix("mod "))?
+                .trim_end()
+                .strip_suffix(';')?;
+            family(declared).then_some((offset, declared))
+        })
+        .collect();
+    if modules.iter().any(|(_, declared)| *declared == module) {
+        return Placement::Exists;
+    }
+    match modules.iter().find(|(_, declared)| *declared > module) {
+        Some((offset, _)) => Placement::At(*offset),
+        None => match modules.last() {
+            Some((offset, _)) => Placement::At(
+                offset
+                    + text[*offset..]
+                        .find('\n')
+                        .map_or(text.len() - offset, |end| end + 1),
+            ),
+            None => Placement::Missing,
+        },
+    }
+}
+
+/// Where to add the arm of `number` to the `match` of `function`, its arms sorted by
+/// number and the fallback `_` closing it.
+fn arm_at(text: &str, function: &str, number: u16) -> Placement {
+    let Some(start) = text.find(function) else {
+        return Placement::Missing;
+    };
+    let mut at = None;
+    for (offset, line) in lines_at(&text[start..]) {
+        let arm = line.trim_start();
+        if arm.starts_with("_ =>") {
+            return Placement::At(at.unwrap_or(start + offset));
+        }
+        match arm.split(" => ").next().and_then(|n| n.parse::<u16>().ok()) {
+            Some(other) if other == number => return Placement::Exists,
+            Some(other) if other > number => at = at.or(Some(start + offset)),
+            _ => {}
+        }
+    }
+    Placement::Missing
+}
+
+/// The module of a year with the module of `puzzle` declared and its solver in
+/// `get_solver`, both in order of the days.
+fn register(module: &str, path: &Path, puzzle: Puzzle) -> Result<String, ScaffoldError> {
+    let name = format!("day{}", puzzle.day);
+    let registered = || ScaffoldError::Registered {
+        puzzle,
+        path: path.to_path_buf(),
+    };
+    let layout = |expected: &str| ScaffoldError::Layout {
+        path: path.to_path_buf(),
+        expected: expected.to_string(),
+    };
+
+    let is_day = |module: &str| {
+        module
+            .strip_prefix("day")
+            .is_some_and(|day| day.parse::<u8>().is_ok())
+    };
+    let module_at = match module_at(module, &name, is_day) {
+        Placement::At(offset) => offset,
+        Placement::Exists => return Err(registered()),
+        Placement::Missing => return Err(layout("`pub mod dayN;` declarations")),
+    };
+    let arm_at = match arm_at(module, "pub fn get_solver", u16::from(puzzle.day)) {
+        Placement::At(offset) => offset,
+        Placement::Exists => return Err(registered()),
+        Placement::Missing => return Err(layout("a `get_solver` function with a `_ =>` arm")),
+    };
+
+    let mut updated = module.to_string();
+    // The arm comes after the module, so inserting it first keeps the module offset
+    updated.insert_str(
+        arm_at,
+        &format!(
+            "        {} => Box::new({}::Day{}),\n",
+            puzzle.day, name, puzzle.day
+        ),
+    );
+    updated.insert_str(module_at, &format!("pub mod {};\n", name));
+    Ok(updated)
+}
+
+/// `lib.rs` with the module of `year` declared, listed in `YEARS` and dispatched to by
+/// `get_solver`.
+fn register_year(lib: &str, path: &Path, year: u16) -> Result<String, ScaffoldError> {
+    let name = format!("y{}", year);
+    let layout = |expected: &str| ScaffoldError::Layout {
+        path: path.to_path_buf(),
+        expected: expected.to_string(),
+    };
+
+    let module_at = match module_at(lib, &name, |_| true) {
+        Placement::At(offset) => offset,
+        Placement::Exists => {
+            return Err(layout(&format!(
+                "no `{}` module without its directory",
+                name
+            )))
+        }
+        Placement::Missing => return Err(layout("`pub mod` declarations")),
+    };
+    let arm_at = match arm_at(lib, "pub fn get_solver", year) {
+        Placement::At(offset) => offset,
+        Placement::Exists => {
+            return Err(layout(&format!(
+                "no `{}` arm without a `{}` module",
+                year, name
+            )))
+        }
+        Placement::Missing => return Err(layout("a `get_solver` function with a `_ =>` arm")),
+    };
+    const YEARS: &str = "pub const YEARS: &[u16] = &[";
+    let years_at = lib
+        .find(YEARS)
+        .map(|offset| offset + YEARS.len())
+        .ok_or_else(|| layout("a `YEARS` constant"))?;
+    let years_end = years_at
+        + lib[years_at..]
+            .find("];")
+            .ok_or_else(|| layout("a `YEARS` constant"))?;
+    let mut years = lib[years_at..years_end]
+        .split(',')
+        .map(str::trim)
+        .filter(|y| !y.is_empty())
+        .map(|y| y.parse::<u16>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| layout("a list of years in `YEARS`"))?;
+    years.push(year);
+    years.sort_unstable();
+    let years = years
+        .iter()
+        .map(u16::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    // From the end of the file, so that every offset stays valid
+    let mut updated = lib.to_string();
+    updated.insert_str(
+        arm_at,
+        &format!(
+            "        {} => {}::get_solver(puzzle.day, params),\n",
+            year, name
+        ),
+    );
+    updated.replace_range(years_at..years_end, &years);
+    updated.insert_str(module_at, &format!("pub mod {};\n", name));
+    Ok(updated)
+}
+
+/// The test file of a year with an ignored test of `puzzle` among the tests of the other
+/// days, in order.
+fn add_test(tests: &str, path: &Path, puzzle: Puzzle) -> Result<String, ScaffoldError> {
+    let day = puzzle.day;
+    if tests.contains(&format!("fn day{}()", day)) {
+        return Err(ScaffoldError::Registered {
+            puzzle,
+            path: path.to_path_buf(),
+        });
+    }
+    let test = test_template(day);
+    let find = |day: u8| tests.find(&format!("\nfn day{}()", day));
+    // Before the test of the next day, or after the one of the previous day
+    let before_next = (day + 1..=u8::MAX)
+        .find_map(find)
+        .and_then(|offset| tests[..offset].rfind("\n#[test]"))
+        .map(|offset| (offset + 1, format!("{}\n", test)));
+    let after_previous = (1..day)
+        .rev()
+        .find_map(find)
+        .and_then(|offset| tests[offset..].find("\n}\n").map(|end| offset + end + 3))
+        .map(|offset| (offset, format!("\n{}", test)));
+
+    let mut updated = tests.to_string();
+    match before_next.or(after_previous) {
+        Some((offset, test)) => updated.insert_str(offset, &test),
+        None => updated.push_str(&format!("\n{}", test)),
+    }
+    Ok(updated)
+}
+
+/// `tests/examples/main.rs` with the test module of `year`.
+fn add_test_module(main: &str, path: &Path, year: u16) -> Result<String, ScaffoldError> {
+    let name = format!("y{}", year);
+    let is_year = |module: &str| module.starts_with('y');
+    let layout = |expected: &str| ScaffoldError::Layout {
+        path: path.to_path_buf(),
+        expected: expected.to_string(),
+    };
+    match module_at(main, &name, is_year) {
+        Placement::At(offset) => {
+            let mut updated = main.to_string();
+            updated.insert_str(offset, &format!("mod {};\n", name));
+            Ok(updated)
+        }
+        Placement::Exists => Err(layout(&format!("no `{}` module without its file", name))),
+        Placement::Missing => Err(layout("`mod yYYYY;` declarations")),
+    }
+}
+
+/// Creates the files of `puzzle` in the crate at `root` and registers it, along with its
+/// year when it is the first day of the year. Everything is checked before anything is
+/// written. Returns the files created or updated.
+pub fn new_day(root: &Path, puzzle: Puzzle) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let year_dir = root.join("src").join(format!("y{}", puzzle.year));
+    let solver = year_dir.join(format!("day{}.rs", puzzle.day));
+    let example = root.join(input::example_path(puzzle));
+    let module = year_dir.join("mod.rs");
+    let lib = root.join("src").join("lib.rs");
+    let tests_dir = root.join("tests").join("examples");
+    let tests = tests_dir.join(format!("y{}.rs", puzzle.year));
+    let tests_main = tests_dir.join("main.rs");
+
+    let new_year = !module.exists();
+    let created = if new_year {
+        vec![&solver, &example, &module, &tests]
+    } else {
+        vec![&solver, &example]
+    };
+    if let Some(path) = created.into_iter().find(|path| path.exists()) {
+        return Err(ScaffoldError::Exists { path: path.clone() });
+    }
+
+    // Every file with its new content, so that nothing is written before all are ready
+    let mut files = vec![
+        (solver.clone(), solver_template(puzzle.day)),
+        (example.clone(), String::new()),
+    ];
+    if new_year {
+        let tested = add_test(&tests_template(puzzle.year), &tests, puzzle)?;
+        files.push((module, year_template(puzzle)));
+        files.push((lib.clone(), register_year(&read(&lib)?, &lib, puzzle.year)?));
+        files.push((tests, tested));
+        let main = add_test_module(&read(&tests_main)?, &tests_main, puzzle.year)?;
+        files.push((tests_main, main));
+    } else {
+        let registered = register(&read(&module)?, &module, puzzle)?;
+        let tested = add_test(&read(&tests)?, &tests, puzzle)?;
+        files.push((module, registered));
+        files.push((tests, tested));
+    }
+
+    for (path, content) in &files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|error| ScaffoldError::Io {
+                path: dir.to_path_buf(),
+                error,
+            })?;
+        }
+        write(path, content)?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
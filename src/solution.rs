@@ -0,0 +1,168 @@
+use std::any::Any;
+use std::fmt;
+use std::io::BufRead;
+
+use serde::{Serialize, Serializer};
+
+use crate::parse::normalize;
+use crate::{ParseError, ReadError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Whether this part should run given a `--part` filter (`None` runs both).
+    pub fn is_selected(self, filter: Option<Part>) -> bool {
+        filter.is_none_or(|part| part == self)
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// The answer of a single part, either a number or a piece of text (e.g. a password).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    /// Wide enough for sums of products of u32 values, such as the scores of day 1
+    Number(u128),
+    Text(String),
+    /// The part has not been solved for this day
+    Unsolved,
+}
+
+impl Answer {
+    /// Name of the variant, as reported in machine-readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Number(_) => "number",
+            Answer::Text(_) => "text",
+            Answer::Unsolved => "unsolved",
+        }
+    }
+}
+
+/// A number, a string, or `null` when unsolved.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(n) => serializer.serialize_u128(*n),
+            Answer::Text(s) => serializer.serialize_str(s),
+            Answer::Unsolved => serializer.serialize_none(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(value.into())
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as u128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// A day of the calendar: parses the puzzle input once and solves both parts on it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    /// Parses an input already [normalized](normalize) by [`Solver::parse`].
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses the input while reading it, for days whose inputs can be too large to hold
+    /// as text. Unlike [`Solution::parse`], the input is not normalized first. `None` when
+    /// the day only parses whole inputs.
+    fn read(&self, _reader: &mut dyn BufRead) -> Option<Result<Self::Input, ReadError>> {
+        None
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer;
+
+    fn part2(&self, _input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// Object-safe counterpart of [`Solution`], so that every day can live in the same registry.
+pub trait Solver {
+    /// Normalizes `input` and parses it with [`Solution::parse`].
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Parses the input while reading it with [`Solution::read`], if the day can.
+    fn read(&self, reader: &mut dyn BufRead) -> Option<Result<Box<dyn Any>, ReadError>>;
+
+    /// Solves `part` on an input previously returned by [`Solver::parse`].
+    fn solve(&self, input: &dyn Any, part: Part) -> Answer;
+}
+
+impl<S> Solver for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Solution::parse(self, &normalize(input)).map(|input| Box::new(input) as Box<dyn Any>)
+    }
+
+    fn read(&self, reader: &mut dyn BufRead) -> Option<Result<Box<dyn Any>, ReadError>> {
+        Solution::read(self, reader).map(|input| input.map(|input| Box::new(input) as Box<dyn Any>))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Answer {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by the same solution");
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Step-by-step pictures of the days that simulate or search on a grid, played in the
+//! terminal by `run --visualize`.
+
+use std::any::Any;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use crate::grid::Grid;
+use crate::runner::{self, RunError};
+use crate::{y2024, Params, Puzzle, Solution, Solver};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+    /// Any other color, e.g. one per type of plant
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Parameters of the ANSI escape sequence setting the foreground color.
+    fn ansi(self) -> String {
+        match self {
+            Color::Red => "31".to_string(),
+            Color::Green => "32".to_string(),
+            Color::Yellow => "33".to_string(),
+            Color::Blue => "34".to_string(),
+            Color::Magenta => "35".to_string(),
+            Color::Cyan => "36".to_string(),
+            Color::Gray => "90".to_string(),
+            Color::Rgb(r, g, b) => format!("38;2;{};{};{}", r, g, b),
+        }
+    }
+
+    /// Red, green and blue components, for images.
+    pub fn rgb(self) -> [u8; 3] {
+        match self {
+            Color::Red => [220, 50, 47],
+            Color::Green => [80, 160, 60],
+            Color::Yellow => [230, 180, 20],
+            Color::Blue => [40, 110, 210],
+            Color::Magenta => [200, 60, 160],
+            Color::Cyan => [40, 170, 180],
+            Color::Gray => [90, 90, 90],
+            Color::Rgb(r, g, b) => [r, g, b],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tile {
+    pub symbol: char,
+    pub color: Option<Color>,
+}
+
+impl Tile {
+    pub fn plain(symbol: char) -> Self {
+        Tile {
+            symbol,
+            color: None,
+        }
+    }
+
+    pub fn colored(symbol: char, color: Color) -> Self {
+        Tile {
+            symbol,
+            color: Some(color),
+        }
+    }
+}
+
+/// One picture of a day, with a caption telling what it shows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub grid: Grid<Tile>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, grid: Grid<Tile>) -> Self {
+        Frame {
+            caption: caption.into(),
+            grid,
+        }
+    }
+
+    /// The caption then the grid, colored with ANSI escape codes.
+    pub fn to_ansi(&self) -> String {
+        let mut text = format!("{}\n", self.caption);
+        let mut current = None;
+        for (coordinate, tile) in self.grid.iter() {
+            if tile.color != current {
+                match tile.color {
+                    Some(color) => text.push_str(&format!("\x1b[{}m", color.ansi())),
+                    None => text.push_str("\x1b[0m"),
+                }
+                current = tile.color;
+            }
+            text.push(tile.symbol);
+            if coordinate.column + 1 == self.grid.width() as i64 {
+                if current.take().is_some() {
+                    text.push_str("\x1b[0m");
+                }
+                text.push('\n');
+            }
+        }
+        text
+    }
+}
+
+/// The caption then the grid, without colors.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.caption)?;
+        write!(f, "{}", self.grid.map(|tile| tile.symbol))
+    }
+}
+
+/// A day whose progress can be pictured, frame by frame.
+pub trait Visualize: Solution {
+    /// Frames of both parts, computed as they are played since simulations can run for
+    /// thousands of steps.
+    fn frames<'a>(&'a self, input: &'a Self::Input) -> Box<dyn Iterator<Item = Frame> + 'a>;
+}
+
+/// Object-safe counterpart of [`Visualize`], like [`Solver`] is of [`Solution`].
+pub trait Visualizer: Solver {
+    /// Frames of an input previously returned by [`Solver::parse`].
+    fn frames<'a>(&'a self, input: &'a dyn Any) -> Box<dyn Iterator<Item = Frame> + 'a>;
+}
+
+impl<S> Visualizer for S
+where
+    S: Visualize,
+    S::Input: 'static,
+{
+    fn frames<'a>(&'a self, input: &'a dyn Any) -> Box<dyn Iterator<Item = Frame> + 'a> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by the same solution");
+        Visualize::frames(self, input)
+    }
+}
+
+pub fn get_visualizer(puzzle: Puzzle, params: &Params) -> Option<Box<dyn Visualizer>> {
+    match puzzle.year {
+        2024 => y2024::get_visualizer(puzzle.day, params),
+        _ => None,
+    }
+}
+
+/// How frames are shown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Playback {
+    /// Pause after each frame
+    pub delay: Duration,
+    /// Wait for a line on stdin after each frame instead of pausing: empty for the next
+    /// frame, a number to skip that many frames, `q` to stop
+    pub step: bool,
+    /// Clear the screen before each frame and color it, for terminals
+    pub ansi: bool,
+}
+
+/// Plays the frames of `puzzle` on the input [`runner::load`] picks. `false` when the
+/// puzzle has no visualization.
+pub fn play(
+    puzzle: Puzzle,
+    params: &Params,
+    input: Option<&Path>,
+    playback: &Playback,
+) -> Result<bool, RunError> {
+    let Some(visualizer) = get_visualizer(puzzle, params) else {
+        return Ok(false);
+    };
+    let (_, content) = runner::load(puzzle, params, input)?;
+    let parsed = visualizer.parse(&content)?;
+
+    let mut stdout = io::stdout().lock();
+    let mut commands = io::stdin().lock().lines();
+    let mut skip = 0;
+    for frame in visualizer.frames(parsed.as_ref()) {
+        if skip > 0 {
+            skip -= 1;
+            continue;
+        }
+        let shown = if playback.ansi {
+            // Back to the top left corner of a cleared screen
+            write!(stdout, "\x1b[H\x1b[2J{}", frame.to_ansi())
+        } else {
+            writeln!(stdout, "{}", frame)
+        };
+        if shown.and_then(|_| stdout.flush()).is_err() {
+            // Closed pipe, e.g. piped into `head`
+            break;
+        }
+
+        if playback.step {
+            match commands.next() {
+                Some(Ok(command)) if command.trim() == "q" => break,
+                Some(Ok(command)) => skip = command.trim().parse().unwrap_or(0),
+                _ => break,
+            }
+        } else {
+            thread::sleep(playback.delay);
+        }
+    }
+    Ok(true)
+}
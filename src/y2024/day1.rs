@@ -0,0 +1,292 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use crate::parse::{lines, Source};
+use crate::{Answer, Metric, Pairs, Params, ParseError, ReadError, Solution, Sort};
+
+/// Compares every pair of columns by the sum of the absolute differences of their numbers,
+/// sorting them with binary heaps, unless the parameters say otherwise. The radix sort also
+/// reads the input line by line.
+pub struct Day1 {
+    sort: Sort,
+    pairs: Pairs,
+    metric: Metric,
+}
+
+impl Day1 {
+    pub fn new(params: &Params) -> Self {
+        Day1 {
+            sort: params.sort.unwrap_or_default(),
+            pairs: params.pairs.unwrap_or_default(),
+            metric: params.metric.unwrap_or_default(),
+        }
+    }
+
+    /// The columns compared, in the order of the answers.
+    fn pairs(&self, columns: usize) -> Vec<(usize, usize)> {
+        match self.pairs {
+            Pairs::Pairwise => (0..columns)
+                .flat_map(|i| (i + 1..columns).map(move |j| (i, j)))
+                .collect(),
+            Pairs::First => (1..columns).map(|j| (0, j)).collect(),
+        }
+    }
+
+    /// Distance between two columns whose numbers come paired from the smallest up.
+    fn distance(&self, pairs: impl Iterator<Item = (u32, u32)>) -> u128 {
+        let differences = pairs.map(|(left, right)| u128::from(left.abs_diff(right)));
+        match self.metric {
+            Metric::Absolute => differences.sum(),
+            Metric::Squared => differences.map(|d| d * d).sum(),
+            Metric::Max => differences.max().unwrap_or(0),
+        }
+    }
+}
+
+/// The columns of the input, at least two and all of the same length, already sorted when
+/// read for the radix sort.
+pub struct Lists {
+    columns: Vec<Vec<u32>>,
+}
+
+impl Lists {
+    /// Lists of `columns`, two empty ones for an empty input.
+    fn new(columns: Vec<Vec<u32>>) -> Self {
+        if columns.is_empty() {
+            Lists {
+                columns: vec![Vec::new(); 2],
+            }
+        } else {
+            Lists { columns }
+        }
+    }
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Lists;
+
+    fn parse(&self, input: &str) -> Result<Lists, ParseError> {
+        if self.sort == Sort::Radix {
+            return match read_lists(&mut input.as_bytes()) {
+                Ok(lists) => Ok(lists),
+                Err(ReadError::Parse(e)) => Err(e),
+                Err(ReadError::Io(e)) => unreachable!("reading a string cannot fail: {}", e),
+            };
+        }
+
+        let source = Source::new(Self::DAY, input);
+        let mut columns = Vec::new();
+
+        for line in lines(input) {
+            parse_line(&source, line, &mut columns)?;
+        }
+
+        Ok(Lists::new(columns))
+    }
+
+    fn read(&self, reader: &mut dyn BufRead) -> Option<Result<Lists, ReadError>> {
+        match self.sort {
+            Sort::Heap => None,
+            Sort::Radix => Some(read_lists(reader)),
+        }
+    }
+
+    fn part1(&self, lists: &Lists) -> Answer {
+        let distances = self.pairs(lists.columns.len()).into_iter().map(|(i, j)| {
+            let (left, right) = (&lists.columns[i], &lists.columns[j]);
+            match self.sort {
+                Sort::Heap => self.distance(popped_in_order(left, right)),
+                Sort::Radix => self.distance(left.iter().copied().zip(right.iter().copied())),
+            }
+        });
+
+        answer(distances)
+    }
+
+    fn part2(&self, lists: &Lists) -> Answer {
+        let scores = self.pairs(lists.columns.len()).into_iter().map(|(i, j)| {
+            let (left, right) = (&lists.columns[i], &lists.columns[j]);
+            match self.sort {
+                Sort::Heap => similarity_score(left, right),
+                Sort::Radix => sorted_similarity_score(left, right),
+            }
+        });
+
+        answer(scores)
+    }
+}
+
+/// The number of a single pair of columns, or the numbers of every pair separated by commas.
+fn answer(values: impl Iterator<Item = u128>) -> Answer {
+    let values: Vec<u128> = values.collect();
+    match values[..] {
+        [value] => value.into(),
+        _ => values
+            .iter()
+            .map(u128::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+            .into(),
+    }
+}
+
+/// Adds the numbers of `line` to `columns`, the first line setting how many there are.
+fn parse_line(source: &Source, line: &str, columns: &mut Vec<Vec<u32>>) -> Result<(), ParseError> {
+    let mut numbers = line.split_whitespace();
+
+    if columns.is_empty() {
+        for number in numbers {
+            columns.push(vec![source.number(number)?]);
+        }
+        return match columns.len() {
+            0 => Err(source.missing(line, "a location ID")),
+            1 => Err(source.missing(line, "a second location ID")),
+            _ => Ok(()),
+        };
+    }
+
+    let count = columns.len();
+    for (i, column) in columns.iter_mut().enumerate() {
+        let what = match (i, count) {
+            (0, _) => "a location ID".to_string(),
+            (1, 2) => "a second location ID".to_string(),
+            _ => format!("location ID {} of {}", i + 1, count),
+        };
+        column.push(source.number(source.next(&mut numbers, line, &what)?)?);
+    }
+    match numbers.next() {
+        Some(extra) => Err(source.error(extra, format!("expected {} location IDs", count))),
+        None => Ok(()),
+    }
+}
+
+/// The numbers of both lists paired from the smallest up, popped from binary heaps.
+fn popped_in_order(left: &[u32], right: &[u32]) -> impl Iterator<Item = (u32, u32)> {
+    let mut left_heap: BinaryHeap<Reverse<u32>> = left.iter().copied().map(Reverse).collect();
+    let mut right_heap: BinaryHeap<Reverse<u32>> = right.iter().copied().map(Reverse).collect();
+
+    std::iter::from_fn(move || match (left_heap.pop(), right_heap.pop()) {
+        (Some(Reverse(left_number)), Some(Reverse(right_number))) => {
+            Some((left_number, right_number))
+        }
+        _ => None,
+    })
+}
+
+fn similarity_score(left: &[u32], right: &[u32]) -> u128 {
+    let mut count_right_occurences: HashMap<u32, u32> = HashMap::new();
+    for right_num in right {
+        *count_right_occurences.entry(*right_num).or_insert(0) += 1;
+    }
+
+    left.iter()
+        .map(|left_number| {
+            let left_occurence_in_right = count_right_occurences.get(left_number).unwrap_or(&0);
+            u128::from(*left_number) * u128::from(*left_occurence_in_right)
+        })
+        .sum()
+}
+
+/// Reads the lists one line at a time, cleaning up the lines as [`normalize`] would, then
+/// radix sorts them.
+///
+/// [`normalize`]: crate::parse::normalize
+fn read_lists(reader: &mut dyn BufRead) -> Result<Lists, ReadError> {
+    let mut columns = Vec::new();
+    let mut buffer = String::new();
+    let mut line_number = 0;
+    // First of the blank lines since the last numbers, only allowed at the end of the input
+    let mut blank = None;
+
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            break;
+        }
+        line_number += 1;
+        let mut line = buffer.trim_end();
+        if line_number == 1 {
+            line = line.strip_prefix('\u{feff}').unwrap_or(line);
+        }
+        if line.is_empty() {
+            blank.get_or_insert(line_number);
+            continue;
+        }
+        if let Some(blank) = blank {
+            let error = Source::new(Day1::DAY, "").missing("", "a location ID");
+            return Err(ParseError {
+                line: blank,
+                ..error
+            }
+            .into());
+        }
+
+        parse_line(&Source::new(Day1::DAY, line), line, &mut columns).map_err(|error| {
+            ParseError {
+                line: line_number,
+                ..error
+            }
+        })?;
+    }
+
+    let mut scratch = Vec::new();
+    for column in &mut columns {
+        radix_sort(column, &mut scratch);
+    }
+    Ok(Lists::new(columns))
+}
+
+/// Sorts `values` one byte at a time from the lowest, skipping the bytes that all of them
+/// share. `scratch` holds the values between two passes.
+fn radix_sort(values: &mut Vec<u32>, scratch: &mut Vec<u32>) {
+    let mut counts = [[0usize; 256]; 4];
+    for &value in values.iter() {
+        for (byte, counts) in counts.iter_mut().enumerate() {
+            counts[(value >> (8 * byte)) as usize & 0xff] += 1;
+        }
+    }
+
+    scratch.resize(values.len(), 0);
+    for (byte, counts) in counts.iter().enumerate() {
+        if counts.contains(&values.len()) {
+            continue;
+        }
+        let mut offsets = [0; 256];
+        for digit in 1..256 {
+            offsets[digit] = offsets[digit - 1] + counts[digit - 1];
+        }
+        for &value in values.iter() {
+            let digit = (value >> (8 * byte)) as usize & 0xff;
+            scratch[offsets[digit]] = value;
+            offsets[digit] += 1;
+        }
+        std::mem::swap(values, scratch);
+    }
+}
+
+/// The similarity score of two sorted lists, walking both at once.
+fn sorted_similarity_score(left: &[u32], right: &[u32]) -> u128 {
+    let (mut i, mut j) = (0, 0);
+    let mut similarity_score = 0;
+
+    while i < left.len() && j < right.len() {
+        match left[i].cmp(&right[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                let number = left[i];
+                let in_left = left[i..].partition_point(|&n| n == number);
+                let in_right = right[j..].partition_point(|&n| n == number);
+                similarity_score += u128::from(number) * in_left as u128 * in_right as u128;
+                i += in_left;
+                j += in_right;
+            }
+        }
+    }
+
+    similarity_score
+}
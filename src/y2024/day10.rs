@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+use crate::geometry::Vec2;
+use crate::grid::Grid;
+use crate::parse::Source;
+use crate::{Answer, ParseError, Solution};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Copy)]
+struct Place {
+    coordinate: Vec2,
+    value: u32,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Grid<u32>;
+
+    fn parse(&self, input: &str) -> Result<Grid<u32>, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        Grid::parse(&source, input, |n| n.to_digit(10), "a height from 0 to 9")
+    }
+
+    fn part1(&self, places: &Grid<u32>) -> Answer {
+        calculate_hiking_score(places, false).into()
+    }
+
+    fn part2(&self, places: &Grid<u32>) -> Answer {
+        calculate_hiking_score(places, true).into()
+    }
+}
+
+fn calculate_hiking_score(positions: &Grid<u32>, support_ratings: bool) -> u32 {
+    positions
+        .iter()
+        .filter(|(_, v)| **v == 0)
+        .map(|(k, v)| {
+            let mut visited_places: HashSet<Place> = HashSet::new();
+            let start_place = Place {
+                coordinate: k,
+                value: *v,
+            };
+            get_hiking_trails_score(positions, &mut visited_places, start_place, support_ratings)
+        })
+        .sum()
+}
+
+fn get_hiking_trails_score(
+    positions: &Grid<u32>,
+    visited_peaks: &mut HashSet<Place>,
+    place: Place,
+    support_ratings: bool,
+) -> u32 {
+    if place.value == 9 {
+        if !visited_peaks.contains(&place) {
+            if !support_ratings {
+                visited_peaks.insert(place);
+            }
+            return 1;
+        } else {
+            return 0;
+        };
+    }
+
+    positions
+        .neighbors4(place.coordinate)
+        .filter_map(|new_coord| {
+            positions.get(new_coord).and_then(|&v| {
+                (v == place.value + 1).then(|| {
+                    get_hiking_trails_score(
+                        positions,
+                        visited_peaks,
+                        Place {
+                            coordinate: new_coord,
+                            value: v,
+                        },
+                        support_ratings,
+                    )
+                })
+            })
+        })
+        .sum()
+}
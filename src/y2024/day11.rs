@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use crate::parse::Source;
+use crate::{Answer, Params, ParseError, Solution};
+
+pub(crate) const BLINKS: usize = 75;
+
+/// Part 2 blinks 75 times unless given another number of blinks.
+pub struct Day11 {
+    blinks: usize,
+}
+
+impl Day11 {
+    pub fn new(params: &Params) -> Self {
+        Day11 {
+            blinks: params.blinks.unwrap_or(BLINKS),
+        }
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        input
+            .split_whitespace()
+            .map(|stone| source.number(stone))
+            .collect()
+    }
+
+    fn part1(&self, stones: &Vec<u64>) -> Answer {
+        part1(stones).into()
+    }
+
+    fn part2(&self, stones: &Vec<u64>) -> Answer {
+        part2(stones, self.blinks).into()
+    }
+}
+
+fn part1(stones: &[u64]) -> usize {
+    (0..25)
+        .fold(stones.to_vec(), |stones, _| {
+            stones.iter().flat_map(blink).collect()
+        })
+        .len()
+}
+
+fn part2(stones: &[u64], blinks: usize) -> usize {
+    let mut counts: HashMap<u64, usize> = HashMap::new();
+    for &stone in stones {
+        *counts.entry(stone).or_insert(0) += 1;
+    }
+
+    (0..blinks)
+        .fold(counts, |stones, _| generate_stone_map(&stones))
+        .values()
+        .sum()
+}
+
+fn generate_stone_map(stones: &HashMap<u64, usize>) -> HashMap<u64, usize> {
+    let mut partial_stones: HashMap<u64, usize> = HashMap::new();
+
+    stones.iter().for_each(|(stone, count)| {
+        for new_stone in blink(stone) {
+            partial_stones
+                .entry(new_stone)
+                .and_modify(|new_count| *new_count += *count)
+                .or_insert(*count);
+        }
+    });
+    partial_stones
+}
+
+fn blink(n: &u64) -> Vec<u64> {
+    match *n {
+        0 => vec![1],
+        n => half_of_even_length(n)
+            .map(|half| vec![n / half, n % half])
+            .unwrap_or_else(|| vec![n * 2024]),
+    }
+}
+
+/// 10 to the power of half the digits of `n`, when it has an even number of them.
+fn half_of_even_length(n: u64) -> Option<u64> {
+    let length = n.ilog10() + 1;
+
+    if !length.is_multiple_of(2) {
+        return None;
+    }
+
+    Some(10u64.pow(length / 2))
+}
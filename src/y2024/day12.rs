@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use crate::geometry::{Direction4, Vec2};
+use crate::grid::Grid;
+use crate::parse::Source;
+use crate::visualize::{Color, Frame, Tile, Visualize};
+use crate::{Answer, ParseError, Solution};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct GardenPlot {
+    coordinate: Vec2,
+    value: char,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Region {
+    plots: Vec<GardenPlot>,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+
+    // Any character names a type of plant
+    fn parse(&self, input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(&Source::new(Self::DAY, input), input, Some, "a plant")
+    }
+
+    fn part1(&self, garden_map: &Grid<char>) -> Answer {
+        calculate_fencing_price(garden_map).into()
+    }
+}
+
+/// Colors told apart at a glance, one per type of plant. Types past the palette share
+/// colors, which neighboring regions rarely do.
+const PLANT_COLORS: [Color; 12] = [
+    Color::Rgb(230, 25, 75),
+    Color::Rgb(60, 180, 75),
+    Color::Rgb(255, 200, 25),
+    Color::Rgb(0, 130, 200),
+    Color::Rgb(245, 130, 48),
+    Color::Rgb(145, 30, 180),
+    Color::Rgb(70, 200, 200),
+    Color::Rgb(240, 50, 230),
+    Color::Rgb(160, 200, 60),
+    Color::Rgb(250, 160, 190),
+    Color::Rgb(0, 128, 128),
+    Color::Rgb(170, 110, 40),
+];
+
+/// The garden with its plots colored by type of plant.
+impl Visualize for Day12 {
+    fn frames<'a>(&'a self, garden_map: &'a Grid<char>) -> Box<dyn Iterator<Item = Frame> + 'a> {
+        Box::new(std::iter::once_with(|| {
+            let grid = garden_map.map(|&plant| {
+                Tile::colored(plant, PLANT_COLORS[plant as usize % PLANT_COLORS.len()])
+            });
+            Frame::new(
+                format!("fencing price {}", calculate_fencing_price(garden_map)),
+                grid,
+            )
+        }))
+    }
+}
+
+fn calculate_fencing_price(garden_map: &Grid<char>) -> u32 {
+    let mut visited_plots: HashSet<Vec2> = HashSet::new();
+    let mut total = 0;
+
+    for (coord, value) in garden_map.iter() {
+        if !visited_plots.contains(&coord) {
+            let plot = GardenPlot {
+                coordinate: coord,
+                value: *value,
+            };
+            let mut region = Region { plots: vec![] };
+            region = get_region(garden_map, plot, &mut region, &mut visited_plots);
+
+            let area = region.plots.len() as u32;
+            let perimeter = calculate_perimeter(garden_map, region.plots);
+            total += area * perimeter;
+        }
+    }
+
+    total
+}
+
+fn get_region(
+    garden_map: &Grid<char>,
+    plot: GardenPlot,
+    region: &mut Region,
+    visited_plots: &mut HashSet<Vec2>,
+) -> Region {
+    if visited_plots.contains(&plot.coordinate) {
+        return region.clone();
+    }
+
+    visited_plots.insert(plot.coordinate);
+    region.plots.push(plot);
+    let neighbours = get_neighbours(garden_map, region.plots.last().unwrap());
+
+    neighbours.iter().for_each(|n| {
+        get_region(garden_map, n.clone(), region, visited_plots);
+    });
+
+    region.clone()
+}
+
+fn get_neighbours(garden_map: &Grid<char>, plot: &GardenPlot) -> Vec<GardenPlot> {
+    garden_map
+        .neighbors4(plot.coordinate)
+        .filter_map(|coord| {
+            garden_map.get(coord).and_then(|p| {
+                (*p == plot.value).then_some(GardenPlot {
+                    coordinate: coord,
+                    value: *p,
+                })
+            })
+        })
+        .collect()
+}
+
+fn calculate_perimeter(garden_map: &Grid<char>, plots: Vec<GardenPlot>) -> u32 {
+    let mut perimeter = 0;
+
+    plots.iter().for_each(|plot| {
+        for direction in Direction4::ALL {
+            match garden_map.get(plot.coordinate + direction.offset()) {
+                Some(p) if plot.value == *p => continue,
+                _ => perimeter += 1,
+            };
+        }
+    });
+    perimeter
+}
@@ -0,0 +1,122 @@
+use regex::RegexBuilder;
+
+use crate::parse::{lines, sections, Source};
+use crate::{Answer, ParseError, Solution};
+
+#[derive(Clone, Debug)]
+struct ButtonA {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Clone, Debug)]
+struct ButtonB {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Clone, Debug)]
+struct Prize {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct Machine {
+    button_a: ButtonA,
+    button_b: ButtonB,
+    prize: Prize,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Machine>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Machine>, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let raw_machines: Vec<&str> = sections(input).collect();
+        let re = RegexBuilder::new(r"(?s)Button A: X\+(?<Xa>\d+), Y\+(?<Ya>\d+)\nButton B: X\+(?<Xb>\d+), Y\+(?<Yb>\d+)\nPrize: X=(?<Xf>\d+), Y=(?<Yf>\d+)")
+            .multi_line(true)
+            .build()
+            .unwrap();
+
+        raw_machines
+            .iter()
+            .map(|m| {
+                let caps = re.captures(m).ok_or_else(|| {
+                    source.error(
+                        lines(m).next().unwrap_or(m),
+                        "expected a machine with buttons A and B and a prize",
+                    )
+                })?;
+                let number = |name: &str| source.number(caps.name(name).unwrap().as_str());
+                Ok(Machine {
+                    button_a: ButtonA {
+                        x: number("Xa")?,
+                        y: number("Ya")?,
+                    },
+                    button_b: ButtonB {
+                        x: number("Xb")?,
+                        y: number("Yb")?,
+                    },
+                    prize: Prize {
+                        x: number("Xf")?,
+                        y: number("Yf")?,
+                    },
+                })
+            })
+            .collect()
+    }
+
+    fn part1(&self, machines: &Vec<Machine>) -> Answer {
+        (part1(machines) as u64).into()
+    }
+
+    fn part2(&self, machines: &Vec<Machine>) -> Answer {
+        (part2(machines) as u64).into()
+    }
+}
+
+fn part1(machines: &[Machine]) -> f64 {
+    machines.iter().map(calculate_tokens).sum()
+}
+
+fn part2(machines: &[Machine]) -> f64 {
+    machines
+        .iter()
+        .map(|m| {
+            let new_prize = Prize {
+                x: m.prize.x + 10000000000000.0,
+                y: m.prize.y + 10000000000000.0,
+            };
+
+            Machine {
+                button_a: m.button_a.clone(),
+                button_b: m.button_b.clone(),
+                prize: new_prize,
+            }
+        })
+        .map(|m| calculate_tokens(&m))
+        .sum()
+}
+
+fn calculate_tokens(m: &Machine) -> f64 {
+    let (xa, ya) = (m.button_a.x, m.button_a.y);
+    let (xb, yb) = (m.button_b.x, m.button_b.y);
+    let (xf, yf) = (m.prize.x, m.prize.y);
+    let b_pushes: f64 = (xa * yf - ya * xf) / (yb * xa - xb * ya);
+    let a_pushes: f64 = (xf - xb * b_pushes) / xa;
+
+    // Buttons can only be pushed a whole and non-negative number of times
+    let possible = [a_pushes, b_pushes]
+        .iter()
+        .all(|pushes| pushes.fract() == 0.0 && *pushes >= 0.0);
+    if possible {
+        a_pushes * 3.0 + b_pushes
+    } else {
+        0.0
+    }
+}
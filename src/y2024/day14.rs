@@ -0,0 +1,198 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::geometry::Vec2;
+use crate::grid::Grid;
+use crate::parse::{lines, Source};
+use crate::visualize::{Color, Frame, Tile, Visualize};
+use crate::{Answer, Params, ParseError, Solution};
+
+#[derive(Clone, Debug)]
+pub struct Robot {
+    position: Vec2,
+    velocity: Vec2,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Size {
+    width: i64,
+    height: i64,
+}
+
+impl Size {
+    /// Seconds after which every robot is back where it started, whatever its velocity.
+    fn seconds(self) -> i64 {
+        self.width * self.height
+    }
+}
+
+pub struct Bathroom {
+    robots: Vec<Robot>,
+    size: Size,
+}
+
+/// The bathroom is 11x7 in the example and 101x103 in the puzzle, unless given.
+pub struct Day14 {
+    size: Size,
+}
+
+impl Day14 {
+    pub fn new(params: &Params) -> Self {
+        Day14 {
+            size: Size {
+                width: params.or_default(params.width, 11, 101),
+                height: params.or_default(params.height, 7, 103),
+            },
+        }
+    }
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Bathroom;
+
+    fn parse(&self, input: &str) -> Result<Bathroom, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        // Parses `name=x,y`, `x` being the column and `y` the line
+        let pair = |field: &str, name: &str| -> Result<Vec2, ParseError> {
+            let values = field
+                .strip_prefix(name)
+                .and_then(|f| f.strip_prefix('='))
+                .ok_or_else(|| source.error(field, format!("expected `{}=x,y`", name)))?;
+            let mut values = values.split(',');
+            let x = source.number(source.next(&mut values, field, "x")?)?;
+            let y = source.number(source.next(&mut values, field, "`,y`")?)?;
+
+            Ok(Vec2::new(y, x))
+        };
+
+        let size = self.size;
+        let robots: Vec<Robot> = lines(input)
+            .map(|data| {
+                let mut d = data.split(' ');
+
+                let raw_position = source.next(&mut d, data, "a position")?;
+                let position = pair(raw_position, "p")?;
+                let velocity = pair(source.next(&mut d, data, "a velocity")?, "v")?;
+
+                if !(0..size.width).contains(&position.column)
+                    || !(0..size.height).contains(&position.line)
+                {
+                    return Err(source.error(
+                        raw_position,
+                        format!(
+                            "expected a robot inside the {}x{} bathroom",
+                            size.width, size.height
+                        ),
+                    ));
+                }
+                Ok(Robot { position, velocity })
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Bathroom { robots, size })
+    }
+
+    fn part1(&self, bathroom: &Bathroom) -> Answer {
+        part1(&bathroom.robots, bathroom.size).into()
+    }
+
+    fn part2(&self, bathroom: &Bathroom) -> Answer {
+        part2(&bathroom.robots, bathroom.size).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+/// The robots second by second, until they draw the Christmas tree or are back where they
+/// started.
+impl Visualize for Day14 {
+    fn frames<'a>(&'a self, bathroom: &'a Bathroom) -> Box<dyn Iterator<Item = Frame> + 'a> {
+        let size = bathroom.size;
+        let mut tree = false;
+        Box::new((0..=size.seconds()).map_while(move |second| {
+            if tree {
+                return None;
+            }
+            let robots: Vec<Robot> = bathroom
+                .robots
+                .iter()
+                .map(|r| calculate_next_position(r, Some(second), size))
+                .collect();
+            tree = second > 0 && xmas_tree_iteration(&robots);
+
+            let mut counts = Grid::new(size.width as usize, size.height as usize, 0);
+            robots.iter().for_each(|r| counts[r.position] += 1);
+            let grid = counts.map(|&count| match count {
+                0 => Tile::colored('.', Color::Gray),
+                1..=9 => Tile::colored(char::from_digit(count, 10).unwrap(), Color::Green),
+                _ => Tile::colored('+', Color::Green),
+            });
+            let caption = match second {
+                _ if tree => format!("second {}, every robot on its own tile", second),
+                100 => format!(
+                    "second 100, safety factor {}",
+                    part1(&bathroom.robots, size)
+                ),
+                _ => format!("second {}", second),
+            };
+            Some(Frame::new(caption, grid))
+        }))
+    }
+}
+
+fn part1(robots: &[Robot], size: Size) -> u64 {
+    let median_width = size.width / 2;
+    let median_height = size.height / 2;
+    let mut quadrants: HashMap<i64, i64> = HashMap::new();
+    let new_robots: Vec<Robot> = robots
+        .iter()
+        .map(|r| calculate_next_position(r, Some(100), size))
+        .filter(|r| r.position.column != median_width && r.position.line != median_height)
+        .collect();
+
+    for r in new_robots {
+        let quadrant = match (r.position.column, r.position.line) {
+            (x, y) if x < median_width && y < median_height => 1,
+            (_, y) if y < median_height => 2,
+            (x, _) if x < median_width => 3,
+            _ => 4,
+        };
+        quadrants
+            .entry(quadrant)
+            .and_modify(|count| *count += 1)
+            .or_insert(1);
+    }
+
+    quadrants.values().fold(1, |acc, e| acc * (*e as u64))
+}
+
+/// The first second with every robot on its own tile, `None` when the robots are back
+/// where they started without ever getting there.
+fn part2(robots: &[Robot], size: Size) -> Option<u64> {
+    let mut new_robots: Vec<Robot> = robots.to_vec();
+
+    (1..=size.seconds()).find_map(|iteration| {
+        new_robots = new_robots
+            .iter()
+            .map(|r| calculate_next_position(r, None, size))
+            .collect();
+        xmas_tree_iteration(&new_robots).then_some(iteration as u64)
+    })
+}
+
+fn xmas_tree_iteration(robots: &[Robot]) -> bool {
+    let distinct_positions: HashSet<Vec2> = robots.iter().map(|r| r.position).collect();
+
+    distinct_positions.len() == robots.len()
+}
+
+fn calculate_next_position(robot: &Robot, seconds: Option<i64>, size: Size) -> Robot {
+    let position = robot.position + robot.velocity * seconds.unwrap_or(1);
+
+    Robot {
+        position: Vec2::new(
+            position.line.rem_euclid(size.height),
+            position.column.rem_euclid(size.width),
+        ),
+        velocity: robot.velocity,
+    }
+}
@@ -0,0 +1,187 @@
+use crate::geometry::{Direction4, Vec2};
+use crate::grid::Grid;
+use crate::parse::{lines, sections, Source};
+use crate::visualize::{Color, Frame, Tile, Visualize};
+use crate::{Answer, ParseError, Solution};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Copy)]
+enum Status {
+    Wall,
+    Box,
+    Free,
+}
+
+#[derive(Clone, Debug)]
+struct Cell {
+    coordinate: Vec2,
+    status: Status,
+}
+
+#[derive(Clone, Debug, Copy)]
+struct Robot {
+    coordinate: Vec2,
+}
+
+pub struct Day15;
+
+pub struct Warehouse {
+    map: Grid<Status>,
+    robot: Robot,
+    instructions: Vec<Direction4>,
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Warehouse;
+
+    fn parse(&self, input: &str) -> Result<Warehouse, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let mut data = sections(input);
+        let map = source.next(&mut data, input, "a warehouse map")?;
+        let moves = source.next(&mut data, map, "a blank line followed by the moves")?;
+
+        let grid = Grid::parse(
+            &source,
+            map,
+            |c| get_status(c).map(|_| c),
+            "`#`, `.`, `O` or `@`",
+        )?;
+        let mut robots = grid.iter().filter(|(_, c)| **c == '@').map(|(c, _)| c);
+        let robot = match (robots.next(), robots.next()) {
+            (Some(coordinate), None) => Robot { coordinate },
+            _ => return Err(source.error(map, "expected exactly one robot `@`")),
+        };
+        let instructions = lines(moves)
+            .flat_map(|line| {
+                line.char_indices().map(move |(offset, c)| {
+                    Direction4::from_char(c)
+                        .ok_or_else(|| source.unexpected_char(line, offset, "a move among `<>^v`"))
+                })
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Warehouse {
+            map: grid.map(|&c| get_status(c).expect("checked while parsing the grid")),
+            robot,
+            instructions,
+        })
+    }
+
+    fn part1(&self, warehouse: &Warehouse) -> Answer {
+        (part1(warehouse) as u64).into()
+    }
+}
+
+/// The warehouse after each move of the robot.
+impl Visualize for Day15 {
+    fn frames<'a>(&'a self, warehouse: &'a Warehouse) -> Box<dyn Iterator<Item = Frame> + 'a> {
+        let (mut map, mut robot) = (warehouse.map.clone(), warehouse.robot);
+        let moves = warehouse.instructions.len();
+        let first = picture(&map, robot, "initial state".to_string());
+        let steps = warehouse
+            .instructions
+            .iter()
+            .enumerate()
+            .map(move |(index, &direction)| {
+                move_robot(&mut map, &mut robot, direction);
+                let caption = format!(
+                    "move {}/{} {}, GPS sum {}",
+                    index + 1,
+                    moves,
+                    direction.to_char(),
+                    gps_sum(&map)
+                );
+                picture(&map, robot, caption)
+            });
+        Box::new(std::iter::once(first).chain(steps))
+    }
+}
+
+fn picture(map: &Grid<Status>, robot: Robot, caption: String) -> Frame {
+    let mut grid = map.map(|status| match status {
+        Status::Wall => Tile::colored('#', Color::Gray),
+        Status::Box => Tile::colored('O', Color::Yellow),
+        Status::Free => Tile::plain('.'),
+    });
+    grid[robot.coordinate] = Tile::colored('@', Color::Red);
+    Frame::new(caption, grid)
+}
+
+fn part1(warehouse: &Warehouse) -> i64 {
+    let (mut map, mut robot) = (warehouse.map.clone(), warehouse.robot);
+
+    for &direction in &warehouse.instructions {
+        move_robot(&mut map, &mut robot, direction);
+    }
+
+    gps_sum(&map)
+}
+
+fn move_robot(map: &mut Grid<Status>, robot: &mut Robot, direction: Direction4) {
+    if let Some(cell_to_check) = get_next_cell(robot, map, direction) {
+        if cell_to_check.status == Status::Free {
+            robot.coordinate = cell_to_check.coordinate;
+        } else {
+            if let Some(c) = map.get_mut(robot.coordinate) {
+                *c = Status::Free;
+            }
+            robot.coordinate = cell_to_check.coordinate;
+            update_box_position(&cell_to_check, direction, map);
+        }
+    }
+}
+
+fn gps_sum(map: &Grid<Status>) -> i64 {
+    map.iter()
+        .filter(|(_, v)| **v == Status::Box)
+        .map(|(k, _)| 100 * k.line + k.column)
+        .sum()
+}
+
+fn update_box_position(cell: &Cell, direction: Direction4, map: &mut Grid<Status>) {
+    let initial_box_coord = cell.coordinate;
+
+    let free_space_coord = std::iter::successors(Some(cell.coordinate), |&coord| {
+        Some(coord + direction.offset())
+    })
+    .find(|coord| map.get(*coord) == Some(&Status::Free))
+    .unwrap();
+
+    map[initial_box_coord] = Status::Free;
+    map[free_space_coord] = Status::Box;
+}
+
+fn get_next_cell(robot: &Robot, map: &Grid<Status>, direction: Direction4) -> Option<Cell> {
+    let coordinate_to_check = robot.coordinate + direction.offset();
+
+    map.get(coordinate_to_check)
+        .map(|status| Cell {
+            coordinate: coordinate_to_check,
+            status: *status,
+        })
+        .filter(|c| is_robot_free_to_move(c, direction, map))
+}
+
+fn is_robot_free_to_move(cell: &Cell, direction: Direction4, map: &Grid<Status>) -> bool {
+    match cell.status {
+        Status::Wall => false,
+        Status::Free => true,
+        Status::Box => std::iter::successors(Some(cell.coordinate), |&coord| {
+            Some(coord + direction.offset())
+        })
+        .map_while(|coord| map.get(coord))
+        .take_while(|status| **status != Status::Wall)
+        .any(|status| *status == Status::Free),
+    }
+}
+
+// The robot stands on a free cell
+fn get_status(character: char) -> Option<Status> {
+    match character {
+        '#' => Some(Status::Wall),
+        'O' => Some(Status::Box),
+        '.' | '@' => Some(Status::Free),
+        _ => None,
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+
+use pathfinding::prelude::*;
+
+use crate::geometry::{Direction4, Vec2};
+use crate::grid::Grid;
+use crate::parse::Source;
+use crate::visualize::{Color, Frame, Tile, Visualize};
+use crate::{Answer, ParseError, Solution};
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Reindeer {
+    pos: Vec2,
+    dir: Direction4,
+}
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Status {
+    Free,
+    Wall,
+}
+
+pub struct Day16;
+
+pub struct Maze {
+    initial_reindeer: Reindeer,
+    end_pos: Vec2,
+    maze: Grid<Status>,
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Maze;
+
+    fn parse(&self, input: &str) -> Result<Maze, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let tiles = Grid::parse(
+            &source,
+            input,
+            |c| matches!(c, '#' | '.' | 'S' | 'E').then_some(c),
+            "`#`, `.`, `S` or `E`",
+        )?;
+
+        source.single(input, 'S', "start tile `S`")?;
+        source.single(input, 'E', "end tile `E`")?;
+        let start_pos = tiles.find(|&c| c == 'S').unwrap();
+        let end_pos = tiles.find(|&c| c == 'E').unwrap();
+        let maze = tiles.map(|&c| if c == '#' { Status::Wall } else { Status::Free });
+        let initial_reindeer = Reindeer {
+            pos: start_pos,
+            dir: Direction4::Right,
+        };
+
+        Ok(Maze {
+            initial_reindeer,
+            end_pos,
+            maze,
+        })
+    }
+
+    fn part1(&self, maze: &Maze) -> Answer {
+        part1(&maze.initial_reindeer, maze.end_pos, &maze.maze).into()
+    }
+
+    fn part2(&self, maze: &Maze) -> Answer {
+        let lowest_score = part1(&maze.initial_reindeer, maze.end_pos, &maze.maze);
+        best_path_tiles(
+            &maze.initial_reindeer,
+            maze.end_pos,
+            lowest_score,
+            &maze.maze,
+        )
+        .len()
+        .into()
+    }
+}
+
+/// One path with the lowest score, then the tiles of every path with that score.
+impl Visualize for Day16 {
+    fn frames<'a>(&'a self, maze: &'a Maze) -> Box<dyn Iterator<Item = Frame> + 'a> {
+        let picture = |path: &[(Vec2, Tile)]| {
+            let mut grid = maze.maze.map(|status| match status {
+                Status::Wall => Tile::colored('#', Color::Gray),
+                Status::Free => Tile::plain('.'),
+            });
+            path.iter().for_each(|&(tile, shown)| grid[tile] = shown);
+            grid[maze.initial_reindeer.pos] = Tile::colored('S', Color::Red);
+            grid[maze.end_pos] = Tile::colored('E', Color::Red);
+            grid
+        };
+
+        let lowest = std::iter::once_with(move || {
+            let (path, score) = dijkstra(
+                &maze.initial_reindeer,
+                |reindeer| get_successors(reindeer, &maze.maze),
+                |reindeer| reindeer.pos == maze.end_pos,
+            )
+            .unwrap();
+            let steps: Vec<(Vec2, Tile)> = path
+                .iter()
+                .map(|r| (r.pos, Tile::colored(r.dir.to_char(), Color::Cyan)))
+                .collect();
+            Frame::new(
+                format!("a path with the lowest score, {}", score),
+                picture(&steps),
+            )
+        });
+        let best = std::iter::once_with(move || {
+            let lowest_score = part1(&maze.initial_reindeer, maze.end_pos, &maze.maze);
+            let tiles = best_path_tiles(
+                &maze.initial_reindeer,
+                maze.end_pos,
+                lowest_score,
+                &maze.maze,
+            );
+            let shown: Vec<(Vec2, Tile)> = tiles
+                .iter()
+                .map(|&tile| (tile, Tile::colored('O', Color::Green)))
+                .collect();
+            Frame::new(
+                format!("{} tiles on a best path", tiles.len()),
+                picture(&shown),
+            )
+        });
+        Box::new(lowest.chain(best))
+    }
+}
+
+fn part1(initial_reindeer: &Reindeer, goal: Vec2, maze: &Grid<Status>) -> u32 {
+    dijkstra(
+        initial_reindeer,
+        |reindeer| get_successors(reindeer, maze),
+        |reindeer| reindeer.pos == goal,
+    )
+    .unwrap()
+    .1
+}
+
+fn best_path_tiles(
+    initial_reindeer: &Reindeer,
+    goal: Vec2,
+    target_cost: u32,
+    maze: &Grid<Status>,
+) -> HashSet<Vec2> {
+    let mut visited_tiles: HashSet<Vec2> = HashSet::new();
+    let mut prev_size = 0;
+    let mut k = 1;
+
+    loop {
+        let result = yen(
+            initial_reindeer,
+            |reindeer| get_successors(reindeer, maze),
+            |reindeer| reindeer.pos == goal,
+            k,
+        );
+
+        result
+            .iter()
+            .filter(|path| path.1 == target_cost)
+            .for_each(|path| {
+                path.0.iter().for_each(|r| {
+                    visited_tiles.insert(r.pos);
+                })
+            });
+
+        if visited_tiles.len() == prev_size {
+            break;
+        }
+
+        prev_size = visited_tiles.len();
+        k += 1;
+    }
+
+    visited_tiles
+}
+
+fn get_successors(reindeer: &Reindeer, maze: &Grid<Status>) -> Vec<(Reindeer, u32)> {
+    let mut successors = Vec::new();
+
+    let left_reindeer = Reindeer {
+        pos: reindeer.pos,
+        dir: reindeer.dir.turn_left(),
+    };
+
+    successors.push((left_reindeer, 1000));
+
+    let right_reindeer = Reindeer {
+        pos: reindeer.pos,
+        dir: reindeer.dir.turn_right(),
+    };
+
+    successors.push((right_reindeer, 1000));
+
+    let straight_reindeer = Reindeer {
+        pos: reindeer.pos + reindeer.dir.offset(),
+        dir: reindeer.dir,
+    };
+
+    if is_valid_successor(&straight_reindeer.pos, maze) {
+        successors.push((straight_reindeer, 1));
+    }
+
+    successors
+}
+
+fn is_valid_successor(coord: &Vec2, maze: &Grid<Status>) -> bool {
+    maze.get(*coord) == Some(&Status::Free)
+}
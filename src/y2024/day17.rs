@@ -1,4 +1,7 @@
-use regex::{Regex, RegexBuilder};
+use regex::RegexBuilder;
+
+use crate::parse::{lines, Source};
+use crate::{Answer, ParseError, Solution};
 
 #[derive(Clone, Debug, Copy)]
 struct RegisterA {
@@ -22,6 +25,12 @@ struct Registers {
     reg_c: RegisterC,
 }
 
+/// The initial registers and the program, as 3-bit digits
+pub struct Computer {
+    registers: Registers,
+    program: Vec<char>,
+}
+
 #[derive(Clone, Debug, Copy)]
 struct Operand {
     value: u64,
@@ -36,92 +45,99 @@ enum InstructionPointer {
 #[derive(Clone, Debug, Copy)]
 struct Output(u64);
 
-fn main() {
-    let content = std::fs::read_to_string("src/inputs/input-day17.txt").unwrap();
-    let re = RegexBuilder::new(r"(?s)Register A: (?<RegA>\d+)\nRegister B: (?<RegB>\d+)\nRegister C: (?<RegC>\d+)\n\nProgram: (?<Program>.*)")
-    .multi_line(true)
-    .build()
-    .unwrap();
-
-    let program_output = part1(&content, &re);
-    println!("The program output is {:?}", program_output);
-
-    let reg_a_value = part2(&content, &re);
-    println!(
-        "The lowest positive initial value for register A {:?}",
-        reg_a_value
-    );
-}
-
-fn part1(input: &String, re: &Regex) -> String {
-    let caps = re.captures(&input).unwrap();
-    let mut registers = Registers {
-        reg_a: RegisterA {
-            value: caps["RegA"].parse().unwrap(),
-        },
-        reg_b: RegisterB {
-            value: caps["RegB"].parse().unwrap(),
-        },
-        reg_c: RegisterC {
-            value: caps["RegC"].parse().unwrap(),
-        },
-    };
-
-    let program: Vec<char> = caps["Program"]
-        .to_string()
-        .chars()
-        .filter(|c| c.is_numeric())
-        .collect();
-
-    run_program(&program, &mut registers)
-}
-
-fn part2(input: &String, re: &Regex) -> u64 {
-    let caps = re.captures(&input).unwrap();
-    let program: Vec<char> = caps["Program"]
-        .to_string()
-        .chars()
-        .filter(|c| c.is_numeric())
-        .collect();
-    let reversed_program: Vec<String> = program.iter().map(|c| c.to_string()).rev().collect();
+pub struct Day17;
 
-    let program_length = program.len();
+impl Solution for Day17 {
+    const DAY: u8 = 17;
 
-    let mut reg_a: u64 = 0;
-    for i in 0..program_length {
-        let expected_output: String = reversed_program
-            .clone()
-            .into_iter()
-            .take(i + 1)
-            .rev()
-            .collect::<Vec<String>>()
-            .join(",");
-        let mut computed_output: String;
-
-        loop {
-            let mut registers = Registers {
-                reg_a: RegisterA { value: reg_a },
-                reg_b: RegisterB { value: 0 },
-                reg_c: RegisterC { value: 0 },
-            };
-            computed_output = run_program(&program, &mut registers);
-
-            if i == program_length - 1 && computed_output == expected_output {
-                break;
-            } else if computed_output == expected_output {
-                reg_a = reg_a * 8;
-
-                break;
-            } else {
-                reg_a += 1;
-            }
+    type Input = Computer;
+
+    fn parse(&self, input: &str) -> Result<Computer, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let re = RegexBuilder::new(r"(?s)Register A: (?<RegA>\d+)\nRegister B: (?<RegB>\d+)\nRegister C: (?<RegC>\d+)\n\nProgram: (?<Program>.*)")
+            .multi_line(true)
+            .build()
+            .unwrap();
+        let caps = re.captures(input).ok_or_else(|| {
+            source.error(
+                lines(input).next().unwrap_or(input),
+                "expected registers A, B and C followed by the program",
+            )
+        })?;
+        let register = |name: &str| source.number(caps.name(name).unwrap().as_str());
+        let registers = Registers {
+            reg_a: RegisterA {
+                value: register("RegA")?,
+            },
+            reg_b: RegisterB {
+                value: register("RegB")?,
+            },
+            reg_c: RegisterC {
+                value: register("RegC")?,
+            },
+        };
+
+        let raw_program = caps.name("Program").unwrap().as_str().trim_end();
+        let program = raw_program
+            .split(',')
+            .map(|digit| match digit.chars().collect::<Vec<char>>()[..] {
+                [c @ '0'..='7'] => Ok(c),
+                _ => Err(source.error(digit, "expected a 3-bit number")),
+            })
+            .collect::<Result<Vec<char>, ParseError>>()?;
+        if program.len() % 2 != 0 {
+            return Err(source.missing(raw_program, "an operand after the last opcode"));
         }
+
+        Ok(Computer { registers, program })
     }
 
-    reg_a
+    fn part1(&self, computer: &Computer) -> Answer {
+        part1(computer).into()
+    }
+
+    fn part2(&self, computer: &Computer) -> Answer {
+        part2(computer).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+fn part1(computer: &Computer) -> String {
+    let mut registers = computer.registers;
+
+    run_program(&computer.program, &mut registers)
+}
+
+/// Each loop of the program shifts register A by 3 bits, so the last outputs only
+/// depend on its highest bits: A is searched 3 bits at a time from the end of the
+/// program, backtracking when no 3 bits extend the outputs matched so far.
+fn part2(computer: &Computer) -> Option<u64> {
+    search_register_a(&computer.program, 1, 0)
+}
+
+fn search_register_a(program: &[char], matched: usize, reg_a: u64) -> Option<u64> {
+    let expected_output: String = program[program.len() - matched..]
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    (0..8).map(|bits| reg_a * 8 + bits).find_map(|reg_a| {
+        let mut registers = Registers {
+            reg_a: RegisterA { value: reg_a },
+            reg_b: RegisterB { value: 0 },
+            reg_c: RegisterC { value: 0 },
+        };
+        if run_program(program, &mut registers) != expected_output {
+            None
+        } else if matched == program.len() {
+            Some(reg_a)
+        } else {
+            search_register_a(program, matched + 1, reg_a)
+        }
+    })
 }
 
-fn run_program(program: &Vec<char>, registers: &mut Registers) -> String {
+fn run_program(program: &[char], registers: &mut Registers) -> String {
     let program_length = program.len();
     let mut output: Vec<u64> = Vec::new();
     let mut instruction_pointer = 0;
@@ -0,0 +1,216 @@
+use pathfinding::prelude::dijkstra;
+
+use crate::geometry::Vec2;
+use crate::grid::Grid;
+use crate::parse::{lines, Source};
+use crate::visualize::{Color, Frame, Tile, Visualize};
+use crate::{Answer, Params, ParseError, Solution};
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Status {
+    Free,
+    Corrupted,
+}
+
+const EXAMPLE_BYTES_NUMBER: usize = 12;
+const BYTES_NUMBER: usize = 1024;
+
+/// The memory space is 7x7 in the example and 71x71 in the puzzle: unless given,
+/// the size is inferred from the farthest falling byte.
+pub struct Day18 {
+    width: Option<i64>,
+    height: Option<i64>,
+    bytes_number: usize,
+}
+
+impl Day18 {
+    pub fn new(params: &Params) -> Self {
+        Day18 {
+            width: params.width,
+            height: params.height,
+            bytes_number: params.or_default(params.bytes, EXAMPLE_BYTES_NUMBER, BYTES_NUMBER),
+        }
+    }
+}
+
+pub struct MemorySpace {
+    memory_space: Grid<Status>,
+    byte_positions: Vec<Vec2>,
+    goal: Vec2,
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = MemorySpace;
+
+    fn parse(&self, input: &str) -> Result<MemorySpace, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let byte_positions: Vec<(Vec2, &str)> = lines(input)
+            .map(|text| {
+                let mut raw_coord = text.split(',');
+                let column = source.number::<u32>(source.next(&mut raw_coord, text, "x")?)?;
+                let line = source.number::<u32>(source.next(&mut raw_coord, text, "`,y`")?)?;
+                Ok((Vec2::new(line.into(), column.into()), text))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        let width = self.width.unwrap_or_else(|| {
+            byte_positions
+                .iter()
+                .map(|(c, _)| c.column)
+                .max()
+                .unwrap_or(0)
+                + 1
+        });
+        let height = self.height.unwrap_or_else(|| {
+            byte_positions
+                .iter()
+                .map(|(c, _)| c.line)
+                .max()
+                .unwrap_or(0)
+                + 1
+        });
+        let memory_space = Grid::new(width as usize, height as usize, Status::Free);
+        if let Some((_, text)) = byte_positions
+            .iter()
+            .find(|(c, _)| !memory_space.contains(*c))
+        {
+            return Err(source.error(
+                text,
+                format!(
+                    "expected a byte inside the {}x{} memory space",
+                    width, height
+                ),
+            ));
+        }
+        let byte_positions = byte_positions.into_iter().map(|(c, _)| c).collect();
+
+        Ok(MemorySpace {
+            memory_space,
+            byte_positions,
+            goal: Vec2::new(height - 1, width - 1),
+        })
+    }
+
+    fn part1(&self, memory: &MemorySpace) -> Answer {
+        part1(memory, self.bytes_number).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(&self, memory: &MemorySpace) -> Answer {
+        part2(memory, self.bytes_number).map_or(Answer::Unsolved, |coordinate| {
+            format!("{},{}", coordinate.column, coordinate.line).into()
+        })
+    }
+}
+
+/// The shortest path to the exit as bytes keep falling, until one of them cuts it off.
+impl Visualize for Day18 {
+    fn frames<'a>(&'a self, memory: &'a MemorySpace) -> Box<dyn Iterator<Item = Frame> + 'a> {
+        let fallen = self.bytes_number.min(memory.byte_positions.len());
+        let mut blocked = false;
+        Box::new(
+            (fallen..=memory.byte_positions.len()).map_while(move |fallen| {
+                if blocked {
+                    return None;
+                }
+                let mut space = memory.memory_space.clone();
+                initialize_memory(&mut space, &memory.byte_positions, fallen);
+                let path = dijkstra(
+                    &Vec2::new(0, 0),
+                    |c| get_successors(c, &space),
+                    |end_coord| *end_coord == memory.goal,
+                );
+                blocked = path.is_none();
+
+                let mut grid = space.map(|status| match status {
+                    Status::Free => Tile::plain('.'),
+                    Status::Corrupted => Tile::colored('#', Color::Gray),
+                });
+                for &step in path.iter().flat_map(|(steps, _)| steps) {
+                    grid[step] = Tile::colored('O', Color::Green);
+                }
+                let last = fallen.checked_sub(1).map(|i| memory.byte_positions[i]);
+                if let Some(last) = last {
+                    grid[last] = Tile::colored('#', Color::Red);
+                }
+                let caption = match (&path, last) {
+                    (None, Some(last)) => format!(
+                        "{} bytes fallen, {},{} cuts off the exit",
+                        fallen, last.column, last.line
+                    ),
+                    (None, None) => "no way to the exit".to_string(),
+                    (Some((_, steps)), _) => format!("{} bytes fallen, {} steps", fallen, steps),
+                };
+                Some(Frame::new(caption, grid))
+            }),
+        )
+    }
+}
+
+/// The fewest steps to the exit once `bytes_number` bytes have fallen, `None` when the
+/// fallen bytes already cut it off.
+fn part1(memory_space: &MemorySpace, bytes_number: usize) -> Option<u32> {
+    let mut memory = memory_space.memory_space.clone();
+    initialize_memory(&mut memory, &memory_space.byte_positions, bytes_number);
+
+    shortest_path(&memory, memory_space.goal)
+}
+
+/// The first byte cutting off the exit, looked for after the first `bytes_number` ones
+/// unless the exit is already out of reach by then. `None` when no byte ever does.
+fn part2(memory_space: &MemorySpace, bytes_number: usize) -> Option<Vec2> {
+    let mut memory = memory_space.memory_space.clone();
+    let mut fallen = bytes_number.min(memory_space.byte_positions.len());
+    initialize_memory(&mut memory, &memory_space.byte_positions, fallen);
+    if shortest_path(&memory, memory_space.goal).is_none() {
+        memory = memory_space.memory_space.clone();
+        fallen = 0;
+    }
+    // Nothing to cut off when the exit is out of reach before any byte falls
+    shortest_path(&memory, memory_space.goal)?;
+
+    memory_space
+        .byte_positions
+        .iter()
+        .skip(fallen)
+        .find(|coord| {
+            memory[**coord] = Status::Corrupted;
+            shortest_path(&memory, memory_space.goal).is_none()
+        })
+        .cloned()
+}
+
+/// The fewest steps from the top left corner to `goal`, `None` when either is corrupted or
+/// the bytes wall one off from the other.
+fn shortest_path(memory: &Grid<Status>, goal: Vec2) -> Option<u32> {
+    let start = Vec2::new(0, 0);
+    if memory[start] == Status::Corrupted {
+        return None;
+    }
+    dijkstra(
+        &start,
+        |c| get_successors(c, memory),
+        |end_coord| *end_coord == goal,
+    )
+    .map(|(_, steps)| steps)
+}
+
+fn initialize_memory(
+    memory_space: &mut Grid<Status>,
+    byte_positions: &[Vec2],
+    bytes_number: usize,
+) {
+    byte_positions
+        .iter()
+        .take(bytes_number)
+        .for_each(|c| memory_space[*c] = Status::Corrupted);
+}
+
+fn get_successors(current_node: &Vec2, memory_space: &Grid<Status>) -> Vec<(Vec2, u32)> {
+    memory_space
+        .neighbors4(*current_node)
+        .filter(|next| memory_space[*next] == Status::Free)
+        .map(|next| (next, 1))
+        .collect()
+}
@@ -0,0 +1,116 @@
+use std::collections::{HashMap, HashSet};
+
+use rayon::prelude::*;
+
+use crate::parse::{lines, sections, Source};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day19;
+
+pub struct Towels {
+    patterns: HashSet<String>,
+    designs: Vec<String>,
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Towels;
+
+    fn parse(&self, input: &str) -> Result<Towels, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let mut content = sections(input);
+        let raw_patterns = source.next(&mut content, input, "the towel patterns")?;
+        let raw_designs = source.next(
+            &mut content,
+            raw_patterns,
+            "a blank line followed by the designs",
+        )?;
+
+        for line in lines(raw_patterns).chain(lines(raw_designs)) {
+            if let Some(offset) =
+                line.find(|c| !matches!(c, 'w' | 'u' | 'b' | 'r' | 'g' | ',' | ' '))
+            {
+                return Err(source.unexpected_char(line, offset, "a stripe color among `wubrg`"));
+            }
+        }
+
+        let patterns: HashSet<String> = raw_patterns.split(", ").map(|s| s.to_string()).collect();
+        let designs: Vec<String> = lines(raw_designs).map(|s| s.to_string()).collect();
+
+        Ok(Towels { patterns, designs })
+    }
+
+    fn part1(&self, towels: &Towels) -> Answer {
+        part1(&towels.designs, &towels.patterns).into()
+    }
+
+    fn part2(&self, towels: &Towels) -> Answer {
+        part2(&towels.designs, &towels.patterns).into()
+    }
+}
+
+fn part1(designs: &[String], patterns: &HashSet<String>) -> u64 {
+    designs
+        .par_iter()
+        .filter(|design| is_any_design_possible(design, patterns))
+        .count() as u64
+}
+
+fn part2(designs: &[String], patterns: &HashSet<String>) -> u64 {
+    let mut cache: HashMap<String, u64> = HashMap::new();
+    designs
+        .iter()
+        .map(|design| count_every_possible_design(design, patterns, &mut cache))
+        .sum()
+}
+
+fn count_every_possible_design(
+    design: &str,
+    patterns: &HashSet<String>,
+    cache: &mut HashMap<String, u64>,
+) -> u64 {
+    if let Some(count) = cache.get(design) {
+        return *count;
+    }
+
+    if design.is_empty() {
+        return 1;
+    }
+
+    let result = patterns
+        .iter()
+        .map(|pattern| {
+            design
+                .split_at_checked(pattern.len())
+                .map(|(_, suffix)| {
+                    if design.starts_with(pattern) {
+                        count_every_possible_design(suffix, patterns, cache)
+                    } else {
+                        0
+                    }
+                })
+                .unwrap_or(0)
+        })
+        .sum();
+
+    cache.insert(design.to_string(), result);
+    result
+}
+
+fn is_any_design_possible(design: &str, patterns: &HashSet<String>) -> bool {
+    if design.is_empty() {
+        return true;
+    }
+
+    let result = patterns.iter().any(|pattern| {
+        design
+            .split_at_checked(pattern.len())
+            .map(|(_, suffix)| {
+                design.starts_with(pattern) && is_any_design_possible(suffix, patterns)
+            })
+            .unwrap_or(false)
+    });
+
+    result
+}
@@ -0,0 +1,324 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::explain::Explain;
+use crate::parse::{lines, Source};
+use crate::{Answer, Params, ParseError, Solution, Trend};
+
+/// Safe reports go one way by 1 to 3 at a time, and the Problem Dampener removes a single
+/// level, unless the parameters say otherwise.
+pub struct Day2 {
+    policy: Policy,
+}
+
+impl Day2 {
+    /// # Panics
+    ///
+    /// On parameters [`Policy::new`] rejects, which the CLI reports before solving.
+    pub fn new(params: &Params) -> Self {
+        Day2 {
+            policy: Policy::new(params).unwrap_or_else(|error| panic!("{}", error)),
+        }
+    }
+}
+
+/// What makes a report safe.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Policy {
+    /// Differences allowed between adjacent levels, equal levels being allowed whatever the
+    /// trend when this contains 0
+    pub gaps: RangeInclusive<u32>,
+    pub trend: Trend,
+    /// Levels the Problem Dampener may remove
+    pub removals: usize,
+}
+
+impl Policy {
+    /// The rules of the puzzle with the changes the parameters make to them.
+    pub fn new(params: &Params) -> Result<Self, InvertedGaps> {
+        let policy = Policy::default();
+        let (min_gap, max_gap) = (
+            params.min_gap.unwrap_or(*policy.gaps.start()),
+            params.max_gap.unwrap_or(*policy.gaps.end()),
+        );
+        if min_gap > max_gap {
+            return Err(InvertedGaps { min_gap, max_gap });
+        }
+        Ok(Policy {
+            gaps: min_gap..=max_gap,
+            trend: params.trend.unwrap_or(policy.trend),
+            removals: params.removals.unwrap_or(policy.removals),
+        })
+    }
+}
+
+/// The rules of the puzzle.
+impl Default for Policy {
+    fn default() -> Self {
+        Policy {
+            gaps: 1..=3,
+            trend: Trend::Either,
+            removals: 1,
+        }
+    }
+}
+
+/// A smallest gap between adjacent levels above the largest one, which no report could
+/// ever satisfy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvertedGaps {
+    pub min_gap: u32,
+    pub max_gap: u32,
+}
+
+impl fmt::Display for InvertedGaps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "smallest gap {} is above the largest gap {}",
+            self.min_gap, self.max_gap
+        )
+    }
+}
+
+impl std::error::Error for InvertedGaps {}
+
+/// The first pair of adjacent levels of a report that breaks the rules.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Violation {
+    /// Index of the second level of the pair, counting from 0
+    pub index: usize,
+    pub problem: Problem,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Problem {
+    /// The level turns back in a report that has to be `increasing`, or decreasing, by
+    /// its [`Trend`] or else its first two different levels
+    Direction { increasing: bool },
+    /// The level differs from the previous one by this much, outside of the gaps allowed
+    Gap(u32),
+}
+
+/// What the Problem Dampener makes of a report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Diagnosis {
+    Safe,
+    /// Unsafe as is, but safe without the levels at the `removed` indices: as few as
+    /// possible, the earliest ones when there is a choice
+    Dampened {
+        violation: Violation,
+        removed: Vec<usize>,
+    },
+    Unsafe(Violation),
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.problem {
+            Problem::Direction { increasing } => {
+                let (turn, way) = if increasing {
+                    ("down", "increases")
+                } else {
+                    ("up", "decreases")
+                };
+                write!(
+                    f,
+                    "index {} goes {} while the report {}",
+                    self.index, turn, way
+                )
+            }
+            Problem::Gap(0) => write!(f, "index {} repeats the previous level", self.index),
+            Problem::Gap(gap) => write!(
+                f,
+                "index {} is {} away from the previous level",
+                self.index, gap
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Diagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnosis::Safe => write!(f, "safe"),
+            Diagnosis::Dampened { violation, removed } => {
+                let indices: Vec<String> = removed.iter().map(usize::to_string).collect();
+                write!(
+                    f,
+                    "unsafe, {}; safe once the dampener removes {} {}",
+                    violation,
+                    if removed.len() == 1 {
+                        "index"
+                    } else {
+                        "indices"
+                    },
+                    indices.join(", ")
+                )
+            }
+            Diagnosis::Unsafe(violation) => {
+                write!(f, "unsafe, {}; the dampener cannot make it safe", violation)
+            }
+        }
+    }
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        lines(input)
+            .map(|report| {
+                report
+                    .split_whitespace()
+                    .map(|e| source.number(e))
+                    .collect::<Result<Vec<u32>, _>>()
+            })
+            .collect()
+    }
+
+    fn part1(&self, reports: &Vec<Vec<u32>>) -> Answer {
+        reports
+            .iter()
+            .filter(|report| check(report, &self.policy).is_ok())
+            .count()
+            .into()
+    }
+
+    fn part2(&self, reports: &Vec<Vec<u32>>) -> Answer {
+        reports
+            .iter()
+            .filter(|report| !matches!(diagnose(report, &self.policy), Diagnosis::Unsafe(_)))
+            .count()
+            .into()
+    }
+}
+
+/// One line per report with its levels and why it is safe or not.
+impl Explain for Day2 {
+    fn explain(&self, reports: &Vec<Vec<u32>>) -> Vec<String> {
+        reports
+            .iter()
+            .enumerate()
+            .map(|(i, report)| {
+                let levels: Vec<String> = report.iter().map(u32::to_string).collect();
+                format!(
+                    "line {}: {}: {}",
+                    i + 1,
+                    levels.join(" "),
+                    diagnose(report, &self.policy)
+                )
+            })
+            .collect()
+    }
+}
+
+/// The first pair of levels breaking the rules of `policy`, ignoring the dampener.
+pub fn check(levels: &[u32], policy: &Policy) -> Result<(), Violation> {
+    let increasing = match policy.trend {
+        Trend::Increasing => true,
+        Trend::Decreasing => false,
+        Trend::Either => levels
+            .windows(2)
+            .find(|pair| pair[0] != pair[1])
+            .is_some_and(|pair| pair[0] < pair[1]),
+    };
+    for (i, pair) in levels.windows(2).enumerate() {
+        let (previous, level) = (pair[0], pair[1]);
+        let problem = if !policy.gaps.contains(&previous.abs_diff(level)) {
+            Problem::Gap(previous.abs_diff(level))
+        } else if previous != level && (previous < level) != increasing {
+            Problem::Direction { increasing }
+        } else {
+            continue;
+        };
+        return Err(Violation {
+            index: i + 1,
+            problem,
+        });
+    }
+    Ok(())
+}
+
+/// Checks a report, then looks for the levels the Problem Dampener can remove to make it
+/// safe.
+pub fn diagnose(levels: &[u32], policy: &Policy) -> Diagnosis {
+    let Err(violation) = check(levels, policy) else {
+        return Diagnosis::Safe;
+    };
+    let ways: &[bool] = match policy.trend {
+        Trend::Increasing => &[true],
+        Trend::Decreasing => &[false],
+        Trend::Either => &[true, false],
+    };
+    let removed = ways
+        .iter()
+        .filter_map(|&increasing| removals(levels, policy, increasing))
+        .min_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+
+    match removed {
+        Some(removed) => Diagnosis::Dampened { violation, removed },
+        None => Diagnosis::Unsafe(violation),
+    }
+}
+
+/// The fewest levels, at most `policy.removals` and the earliest ones when there is a
+/// choice, to remove for the others to go up (or down when not `increasing`) by allowed
+/// gaps. Each level has to be followed by one of the `removals + 1` next ones, so this
+/// takes O(n·k) for `n` levels and `k` removals.
+fn removals(levels: &[u32], policy: &Policy, increasing: bool) -> Option<Vec<usize>> {
+    let (n, k) = (levels.len(), policy.removals);
+    if n <= 1 {
+        return Some(Vec::new());
+    }
+    let fits = |i: usize, next: usize| {
+        policy.gaps.contains(&levels[i].abs_diff(levels[next]))
+            && (levels[i] == levels[next] || (levels[i] < levels[next]) == increasing)
+    };
+    // Levels removed after `i` when `i` is kept and followed by `next`, `n` being past the
+    // last level, given the fewest removals after each level
+    let cost = |fewest: &[Option<usize>], i: usize, next: usize| {
+        if next == n {
+            Some(n - 1 - i)
+        } else if fits(i, next) {
+            fewest[next].map(|rest| rest + next - i - 1)
+        } else {
+            None
+        }
+    };
+
+    // Fewest removals after each kept level, `None` beyond `k`
+    let mut fewest = vec![None; n];
+    for i in (0..n).rev() {
+        fewest[i] = (i + 1..=(i + k + 1).min(n))
+            .filter_map(|next| cost(&fewest, i, next))
+            .filter(|&removed| removed <= k)
+            .min();
+    }
+
+    // Removing the earliest levels means keeping the farthest next level that still
+    // leads to the fewest removals
+    let total = (0..=k.min(n - 1))
+        .filter_map(|first| fewest[first].map(|rest| first + rest))
+        .filter(|&removed| removed <= k)
+        .min()?;
+    let mut i = (0..=k.min(n - 1))
+        .rev()
+        .find(|&first| fewest[first].map(|rest| first + rest) == Some(total))
+        .expect("the fewest removals start somewhere");
+    let mut removed: Vec<usize> = (0..i).collect();
+    loop {
+        let next = (i + 1..=(i + k + 1).min(n))
+            .rev()
+            .find(|&next| cost(&fewest, i, next) == fewest[i])
+            .expect("the fewest removals go on somewhere");
+        removed.extend(i + 1..next);
+        if next == n {
+            return Some(removed);
+        }
+        i = next;
+    }
+}
@@ -0,0 +1,194 @@
+use pathfinding::prelude::dijkstra;
+
+use crate::geometry::Vec2;
+use crate::grid::Grid;
+use crate::parse::Source;
+use crate::visualize::{Color, Frame, Tile, Visualize};
+use crate::{Answer, Params, ParseError, Solution};
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Status {
+    Free,
+    Wall,
+}
+
+// The example track is too short to save 100 picoseconds
+const EXAMPLE_PICOSECONDS_TO_SAVE: u32 = 50;
+const PICOSECONDS_TO_SAVE: u32 = 100;
+
+pub struct Day20 {
+    picoseconds_to_save: u32,
+}
+
+impl Day20 {
+    pub fn new(params: &Params) -> Self {
+        Day20 {
+            picoseconds_to_save: picoseconds_to_save(params),
+        }
+    }
+}
+
+pub(crate) fn picoseconds_to_save(params: &Params) -> u32 {
+    params.or_default(
+        params.save,
+        EXAMPLE_PICOSECONDS_TO_SAVE,
+        PICOSECONDS_TO_SAVE,
+    )
+}
+
+pub struct RaceTrack {
+    track: Grid<Status>,
+    start: Vec2,
+    end: Vec2,
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = RaceTrack;
+
+    fn parse(&self, input: &str) -> Result<RaceTrack, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let tiles = Grid::parse(
+            &source,
+            input,
+            |c| matches!(c, '#' | '.' | 'S' | 'E').then_some(c),
+            "`#`, `.`, `S` or `E`",
+        )?;
+        source.single(input, 'S', "start `S`")?;
+        source.single(input, 'E', "end `E`")?;
+        let start = tiles.find(|&c| c == 'S').unwrap();
+        let end = tiles.find(|&c| c == 'E').unwrap();
+        let track = tiles.map(|&c| if c == '#' { Status::Wall } else { Status::Free });
+
+        Ok(RaceTrack { track, start, end })
+    }
+
+    fn part1(&self, race: &RaceTrack) -> Answer {
+        solve(
+            &race.track,
+            race.start,
+            race.end,
+            self.picoseconds_to_save,
+            2,
+        )
+        .into()
+    }
+
+    fn part2(&self, race: &RaceTrack) -> Answer {
+        solve(
+            &race.track,
+            race.start,
+            race.end,
+            self.picoseconds_to_save,
+            20,
+        )
+        .into()
+    }
+}
+
+/// The race without cheating, then the cheat saving the most time in each part.
+impl Visualize for Day20 {
+    fn frames<'a>(&'a self, race: &'a RaceTrack) -> Box<dyn Iterator<Item = Frame> + 'a> {
+        let path = optimal_path(&race.track, race.start, race.end);
+
+        let race_frame = std::iter::once({
+            let grid = picture(race, &path, None);
+            Frame::new(format!("race of {} picoseconds", path.len() - 1), grid)
+        });
+        let cheat_frames = [2, 20].into_iter().map(move |max_cheat_steps| {
+            let allowed = cheats(&path, self.picoseconds_to_save, max_cheat_steps).count();
+            let best = cheats(&path, 1, max_cheat_steps).max_by_key(|&(_, _, saved)| saved);
+            let grid = picture(race, &path, best.map(|(from, to, _)| (from, to)));
+            let caption = match best {
+                Some((from, to, saved)) => format!(
+                    "best cheat of up to {} picoseconds, {},{} to {},{}, saves {}; \
+                     cheats saving at least {}: {}",
+                    max_cheat_steps,
+                    from.column,
+                    from.line,
+                    to.column,
+                    to.line,
+                    saved,
+                    self.picoseconds_to_save,
+                    allowed
+                ),
+                None => format!("no cheat of up to {} picoseconds", max_cheat_steps),
+            };
+            Frame::new(caption, grid)
+        });
+        Box::new(race_frame.chain(cheat_frames))
+    }
+}
+
+/// The track with the race along `path`, and the start and end of a cheat.
+fn picture(race: &RaceTrack, path: &[Vec2], cheat: Option<(Vec2, Vec2)>) -> Grid<Tile> {
+    let mut grid = race.track.map(|status| match status {
+        Status::Wall => Tile::colored('#', Color::Gray),
+        Status::Free => Tile::plain('.'),
+    });
+    path.iter()
+        .for_each(|&tile| grid[tile] = Tile::colored('O', Color::Cyan));
+    grid[race.start] = Tile::colored('S', Color::Red);
+    grid[race.end] = Tile::colored('E', Color::Red);
+    if let Some((from, to)) = cheat {
+        grid[from] = Tile::colored('1', Color::Yellow);
+        grid[to] = Tile::colored('2', Color::Yellow);
+    }
+    grid
+}
+
+fn solve(
+    track: &Grid<Status>,
+    start: Vec2,
+    end: Vec2,
+    picoseconds_to_save: u32,
+    max_cheat_steps: u32,
+) -> u64 {
+    let path = optimal_path(track, start, end);
+    cheats(&path, picoseconds_to_save, max_cheat_steps).count() as u64
+}
+
+fn optimal_path(track: &Grid<Status>, start: Vec2, end: Vec2) -> Vec<Vec2> {
+    dijkstra(
+        &start,
+        |c| get_successors(c, track),
+        |end_coord| *end_coord == end,
+    )
+    .unwrap()
+    .0
+}
+
+/// Cheats of at most `max_cheat_steps` saving at least `picoseconds_to_save`, as their
+/// start and end on the path along with the time they save. The path being the only
+/// one, the index of a tile is the time it takes to reach it.
+fn cheats(
+    path: &[Vec2],
+    picoseconds_to_save: u32,
+    max_cheat_steps: u32,
+) -> impl Iterator<Item = (Vec2, Vec2, u32)> + '_ {
+    path.iter().enumerate().flat_map(move |(i, &start_point)| {
+        path.iter()
+            .enumerate()
+            .skip(i + picoseconds_to_save as usize)
+            .filter_map(move |(j, &end_point)| {
+                let path_distance = (j - i) as u32;
+                let manhattan_distance = start_point.manhattan(end_point) as u32;
+                let saved = path_distance - manhattan_distance;
+
+                (saved >= picoseconds_to_save && manhattan_distance <= max_cheat_steps).then_some((
+                    start_point,
+                    end_point,
+                    saved,
+                ))
+            })
+    })
+}
+
+fn get_successors(current_node: &Vec2, track: &Grid<Status>) -> Vec<(Vec2, u32)> {
+    track
+        .neighbors4(*current_node)
+        .filter(|next| track[*next] == Status::Free)
+        .map(|next| (next, 1))
+        .collect()
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use crate::geometry::Vec2;
+use crate::parse::{lines, Source};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        for line in lines(input) {
+            if let Some(offset) = line.find(|c: char| !c.is_ascii_digit() && c != 'A') {
+                return Err(source.unexpected_char(line, offset, "a digit or `A`"));
+            }
+        }
+
+        Ok(lines(input).map(String::from).collect())
+    }
+
+    fn part1(&self, codes: &Vec<String>) -> Answer {
+        let numpad = create_numpad();
+        let dirpad = create_dirpad();
+
+        codes
+            .iter()
+            .map(|code| {
+                let number = code
+                    .chars()
+                    .filter_map(|c| c.to_digit(10))
+                    .fold(0, |number, digit| number * 10 + u64::from(digit));
+                number * presses(code, &numpad, &dirpad, 2)
+            })
+            .sum::<u64>()
+            .into()
+    }
+}
+
+/// Buttons pressed by the person to type `sequence` on `pad`, through `robots` robots
+/// typing on directional keypads in between. Every robot starts on `A` and ends there,
+/// having just pressed it, so each move is counted on its own.
+fn presses(
+    sequence: &str,
+    pad: &HashMap<char, Vec2>,
+    dirpad: &HashMap<char, Vec2>,
+    robots: usize,
+) -> u64 {
+    std::iter::once('A')
+        .chain(sequence.chars())
+        .zip(sequence.chars())
+        .map(|(from, to)| {
+            moves(pad[&from], pad[&to], pad)
+                .iter()
+                .map(|arrows| match robots {
+                    0 => arrows.len() as u64,
+                    _ => presses(arrows, dirpad, dirpad, robots - 1),
+                })
+                .min()
+                .expect("one of the ways avoids the gap")
+        })
+        .sum()
+}
+
+/// The arrows moving from `from` to `to` on `pad`, then pressing `A`: every horizontal
+/// move then every vertical one, or the other way around, unless that goes over the gap.
+/// Any other shortest way turns more, and costs more to the robot typing it.
+fn moves(from: Vec2, to: Vec2, pad: &HashMap<char, Vec2>) -> Vec<String> {
+    let delta = to - from;
+    let horizontal = if delta.column < 0 { '<' } else { '>' }
+        .to_string()
+        .repeat(delta.column.unsigned_abs() as usize);
+    let vertical = if delta.line < 0 { '^' } else { 'v' }
+        .to_string()
+        .repeat(delta.line.unsigned_abs() as usize);
+    let on_pad = |corner: Vec2| pad.values().any(|&key| key == corner);
+
+    let mut ways = Vec::new();
+    if on_pad(Vec2::new(from.line, to.column)) {
+        ways.push(format!("{}{}A", horizontal, vertical));
+    }
+    if on_pad(Vec2::new(to.line, from.column)) {
+        ways.push(format!("{}{}A", vertical, horizontal));
+    }
+    ways
+}
+
+fn create_numpad() -> HashMap<char, Vec2> {
+    HashMap::from([
+        ('7', Vec2::new(0, 0)),
+        ('8', Vec2::new(0, 1)),
+        ('9', Vec2::new(0, 2)),
+        ('4', Vec2::new(1, 0)),
+        ('5', Vec2::new(1, 1)),
+        ('6', Vec2::new(1, 2)),
+        ('1', Vec2::new(2, 0)),
+        ('2', Vec2::new(2, 1)),
+        ('3', Vec2::new(2, 2)),
+        ('0', Vec2::new(3, 1)),
+        ('A', Vec2::new(3, 2)),
+    ])
+}
+
+fn create_dirpad() -> HashMap<char, Vec2> {
+    HashMap::from([
+        ('^', Vec2::new(0, 1)),
+        ('A', Vec2::new(0, 2)),
+        ('<', Vec2::new(1, 0)),
+        ('v', Vec2::new(1, 1)),
+        ('>', Vec2::new(1, 2)),
+    ])
+}
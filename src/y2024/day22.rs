@@ -1,27 +1,35 @@
+use rayon::prelude::*;
+
+use crate::parse::{lines, Source};
+use crate::{Answer, ParseError, Solution};
+
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 struct SecretNumber(u64);
 
-fn main() {
-    let secrets: Vec<u64> = std::fs::read_to_string("src/inputs/input-day22.txt")
-        .unwrap()
-        .lines()
-        .map(|l| l.to_string().parse::<u64>().unwrap())
-        .collect();
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        lines(input).map(|l| source.number(l)).collect()
+    }
 
-    let secrets_sum = part1(&secrets);
-    println!(
-        "The sum of the 2000th secret number generated by each buyer is {}",
-        secrets_sum
-    );
+    fn part1(&self, secrets: &Vec<u64>) -> Answer {
+        part1(secrets).into()
+    }
 }
 
-fn part1(secrets: &Vec<u64>) -> u64 {
+fn part1(secrets: &[u64]) -> u64 {
     secrets
-        .iter()
+        .par_iter()
         .map(|n| {
             let mut init = SecretNumber(*n);
             for _ in 0..2000 {
-                let r = generate_next_number(init.clone());
+                let r = generate_next_number(init);
                 init = r;
             }
             init.0
@@ -0,0 +1,127 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::parse::{lines, Source};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = HashMap<String, HashSet<String>>;
+
+    fn parse(&self, input: &str) -> Result<HashMap<String, HashSet<String>>, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let mut connections: HashMap<String, HashSet<String>> = HashMap::new();
+        let computer = |name: &str| {
+            if name.is_empty() {
+                Err(source.error(name, "expected the name of a computer"))
+            } else {
+                Ok(name.to_string())
+            }
+        };
+
+        for line in lines(input) {
+            let mut parts = line.splitn(3, '-');
+            let comp1 = computer(source.next(&mut parts, line, "a computer")?)?;
+            let comp2 = computer(source.next(&mut parts, line, "`-` and a second computer")?)?;
+            if let Some(extra) = parts.next() {
+                let dash = &line[line.len() - extra.len() - 1..];
+                return Err(source.error(dash, "expected a single `-` between two computers"));
+            }
+
+            connections
+                .entry(comp1.clone())
+                .or_default()
+                .insert(comp2.clone());
+            connections.entry(comp2).or_default().insert(comp1);
+        }
+        if connections.is_empty() {
+            return Err(source.missing(input, "connections like `kh-tc`"));
+        }
+
+        Ok(connections)
+    }
+
+    fn part1(&self, connections: &HashMap<String, HashSet<String>>) -> Answer {
+        part1(&find_triangles(connections)).into()
+    }
+
+    fn part2(&self, connections: &HashMap<String, HashSet<String>>) -> Answer {
+        part2(connections, &find_triangles(connections)).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+fn find_triangles(
+    connections: &HashMap<String, HashSet<String>>,
+) -> HashSet<(String, String, String)> {
+    let mut triangles: HashSet<(String, String, String)> = HashSet::new();
+    for (computer_a, neighbors_a) in connections.iter() {
+        for computer_b in neighbors_a.iter() {
+            for computer_c in connections[computer_b].iter() {
+                let neighbors_c = &connections[computer_c];
+                if neighbors_c.contains(computer_a) {
+                    let mut s = [computer_a, computer_b, computer_c];
+                    s.sort();
+                    let key = (s[0].clone(), s[1].clone(), s[2].clone());
+                    triangles.insert(key);
+                }
+            }
+        }
+    }
+    triangles
+}
+
+fn part1(triangles: &HashSet<(String, String, String)>) -> u64 {
+    triangles
+        .iter()
+        .filter(|(c1, c2, c3)| c1.starts_with("t") || c2.starts_with("t") || c3.starts_with("t"))
+        .count() as u64
+}
+
+/// The password of the largest clique, grown from the triangles, or of the first of the
+/// largest ones in alphabetical order when there are several. `None` without triangles.
+fn part2(
+    connections: &HashMap<String, HashSet<String>>,
+    triangles: &HashSet<(String, String, String)>,
+) -> Option<String> {
+    let mut current_cliques: HashSet<BTreeSet<String>> = triangles
+        .iter()
+        .map(|(c1, c2, c3)| BTreeSet::from([c1.clone(), c2.clone(), c3.clone()]))
+        .collect();
+
+    while current_cliques.len() > 1 {
+        let new_cliques: HashSet<BTreeSet<String>> = current_cliques
+            .iter()
+            .flat_map(|clique| {
+                let last_node = clique.iter().last().unwrap();
+                connections
+                    .keys()
+                    .filter(move |candidate| candidate > &last_node)
+                    .filter(|candidate| {
+                        clique
+                            .iter()
+                            .all(|member| connections[*candidate].contains(member))
+                    })
+                    .map(|candidate| {
+                        let mut new_clique = clique.clone();
+                        new_clique.insert(candidate.clone());
+                        new_clique
+                    })
+            })
+            .collect();
+
+        if new_cliques.is_empty() {
+            break;
+        }
+        current_cliques = new_cliques;
+    }
+
+    current_cliques.iter().min().map(|clique| {
+        clique
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    })
+}
@@ -0,0 +1,207 @@
+use regex::Regex;
+use std::collections::HashMap;
+
+use crate::parse::{lines, sections, Source};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day24;
+
+/// The wires with an initial value and the gates in an order where each gate comes after
+/// the gates driving its operands.
+pub struct Circuit {
+    wires: HashMap<String, u8>,
+    gates: Vec<Gate>,
+}
+
+pub struct Gate {
+    operands: [String; 2],
+    operator: Operator,
+    output: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    And,
+    Or,
+    Xor,
+}
+
+impl Operator {
+    fn apply(self, a: u8, b: u8) -> u8 {
+        match self {
+            Operator::And => a & b,
+            Operator::Or => a | b,
+            Operator::Xor => a ^ b,
+        }
+    }
+}
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Circuit;
+
+    fn parse(&self, input: &str) -> Result<Circuit, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let mut raw_data = sections(input);
+        let raw_wires = source.next(&mut raw_data, input, "the initial wire values")?;
+        let raw_gates = source.next(
+            &mut raw_data,
+            raw_wires,
+            "a blank line followed by the gates",
+        )?;
+
+        let wires: HashMap<String, u8> = lines(raw_wires)
+            .map(|s| {
+                let (wire, value) = s
+                    .split_once(":")
+                    .ok_or_else(|| source.missing(s, "`:` and a value"))?;
+                let value = value.trim();
+                check_output_bit(&source, wire)?;
+                match value {
+                    "0" | "1" => Ok((wire.to_string(), source.number(value)?)),
+                    _ => Err(source.error(value, "expected 0 or 1")),
+                }
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        let re = gate_regex();
+        // Each gate with the slices of its operands and output, to point at them in errors
+        let gates: Vec<(Gate, [&str; 3])> = lines(raw_gates)
+            .map(|l| {
+                let (_, [a, operator, b, output]) = re
+                    .captures(l)
+                    .ok_or_else(|| source.error(l, "expected a gate like `x00 AND y00 -> z00`"))?
+                    .extract();
+                if wires.contains_key(output) {
+                    return Err(source.error(output, "expected a wire without an initial value"));
+                }
+                check_output_bit(&source, output)?;
+                let gate = Gate {
+                    operands: [a.to_string(), b.to_string()],
+                    operator: match operator {
+                        "AND" => Operator::And,
+                        "OR" => Operator::Or,
+                        _ => Operator::Xor,
+                    },
+                    output: output.to_string(),
+                };
+                Ok((gate, [a, b, output]))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        let mut drivers: HashMap<&str, usize> = HashMap::new();
+        for (i, (_, [_, _, output])) in gates.iter().enumerate() {
+            if drivers.insert(output, i).is_some() {
+                return Err(source.error(output, "expected a wire driven by a single gate"));
+            }
+        }
+        for (_, [a, b, _]) in &gates {
+            if let Some(undriven) = [a, b]
+                .into_iter()
+                .find(|o| !wires.contains_key(**o) && !drivers.contains_key(**o))
+            {
+                return Err(source.error(
+                    undriven,
+                    "expected a wire with an initial value or driven by a gate",
+                ));
+            }
+        }
+
+        let order = evaluation_order(&gates, &drivers).map_err(|i| {
+            let [_, _, output] = gates[i].1;
+            source.error(
+                output,
+                "expected gates without loops, this one never gets its inputs",
+            )
+        })?;
+        let mut gates: Vec<Option<Gate>> = gates.into_iter().map(|(gate, _)| Some(gate)).collect();
+        let gates = order.into_iter().filter_map(|i| gates[i].take()).collect();
+
+        Ok(Circuit { wires, gates })
+    }
+
+    fn part1(&self, circuit: &Circuit) -> Answer {
+        let mut wires: HashMap<&str, u8> = circuit
+            .wires
+            .iter()
+            .map(|(wire, &value)| (wire.as_str(), value))
+            .collect();
+
+        for gate in &circuit.gates {
+            let [a, b] = gate.operands.each_ref().map(|o| wires[o.as_str()]);
+            wires.insert(&gate.output, gate.operator.apply(a, b));
+        }
+
+        wires
+            .iter()
+            .filter_map(|(wire, &value)| Some(u64::from(value) << output_bit(wire)?))
+            .sum::<u64>()
+            .into()
+    }
+}
+
+fn gate_regex() -> Regex {
+    Regex::new(
+        "^(?<Operand1>[a-z0-9]{3}) (?<Operator>AND|OR|XOR) (?<Operand2>[a-z0-9]{3}) -> (?<Result>[a-z0-9]{3})$",
+    )
+    .unwrap()
+}
+
+/// The bit of the number read on the `z` wires that `wire` carries, if it is one of them.
+fn output_bit(wire: &str) -> Option<u32> {
+    wire.strip_prefix('z')?.parse().ok()
+}
+
+/// Fails on a `z` wire past the bits of the u64 the answer is read into.
+fn check_output_bit(source: &Source, wire: &str) -> Result<(), ParseError> {
+    match output_bit(wire) {
+        Some(bit) if bit >= u64::BITS => Err(source.error(
+            wire,
+            format!("expected an output bit below {}, not {}", u64::BITS, bit),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// The indices of the gates in an order where each gate comes after the gates driving its
+/// operands, or the index of a gate that never gets its inputs, in or behind a loop.
+fn evaluation_order(
+    gates: &[(Gate, [&str; 3])],
+    drivers: &HashMap<&str, usize>,
+) -> Result<Vec<usize>, usize> {
+    let mut waiting: Vec<usize> = gates
+        .iter()
+        .map(|(gate, _)| {
+            gate.operands
+                .iter()
+                .filter(|o| drivers.contains_key(o.as_str()))
+                .count()
+        })
+        .collect();
+    let mut driven: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (i, (gate, _)) in gates.iter().enumerate() {
+        for operand in &gate.operands {
+            if let Some(&driver) = drivers.get(operand.as_str()) {
+                driven.entry(driver).or_default().push(i);
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..gates.len()).filter(|&i| waiting[i] == 0).collect();
+    let mut next = 0;
+    while let Some(&i) = order.get(next) {
+        next += 1;
+        for &gate in driven.get(&i).into_iter().flatten() {
+            waiting[gate] -= 1;
+            if waiting[gate] == 0 {
+                order.push(gate);
+            }
+        }
+    }
+
+    match waiting.iter().position(|&w| w > 0) {
+        Some(looped) => Err(looped),
+        None => Ok(order),
+    }
+}
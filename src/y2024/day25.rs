@@ -0,0 +1,71 @@
+use crate::parse::{lines, sections, Source};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day25;
+
+pub struct Schematics {
+    lock_heights: Vec<Vec<u32>>,
+    key_heights: Vec<Vec<u32>>,
+}
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Schematics;
+
+    fn parse(&self, input: &str) -> Result<Schematics, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let patterns: Vec<&str> = sections(input).collect();
+
+        for pattern in &patterns {
+            let rows: Vec<&str> = lines(pattern).collect();
+            if rows.len() != 7 {
+                return Err(source.error(pattern, "expected a schematic of 7 rows"));
+            }
+            for line in rows {
+                if let Some(offset) = line.find(|c| c != '#' && c != '.') {
+                    return Err(source.unexpected_char(line, offset, "`#` or `.`"));
+                }
+                if line.len() != 5 {
+                    return Err(source.error(line, "expected a row of 5 columns"));
+                }
+            }
+        }
+
+        let (locks, keys): (Vec<&str>, Vec<&str>) =
+            patterns.iter().partition(|&s| s.starts_with('#'));
+
+        let lock_heights: Vec<Vec<u32>> =
+            locks.iter().map(|&lock| calculate_heights(lock)).collect();
+        let key_heights: Vec<Vec<u32>> = keys.iter().map(|&key| calculate_heights(key)).collect();
+
+        Ok(Schematics {
+            lock_heights,
+            key_heights,
+        })
+    }
+
+    fn part1(&self, schematics: &Schematics) -> Answer {
+        schematics
+            .key_heights
+            .iter()
+            .flat_map(|key| {
+                schematics
+                    .lock_heights
+                    .iter()
+                    .filter(|lock| key.iter().zip(lock.iter()).all(|(k, l)| k + l <= 5))
+            })
+            .count()
+            .into()
+    }
+}
+
+fn calculate_heights(pattern: &str) -> Vec<u32> {
+    let digits: Vec<u32> = lines(pattern)
+        .flat_map(|line| line.chars().map(|c| if c == '.' { 0 } else { 1 }))
+        .collect();
+
+    digits[5..30].chunks(5).fold(vec![0; 5], |acc, chunk| {
+        acc.iter().zip(chunk.iter()).map(|(&a, &b)| a + b).collect()
+    })
+}
@@ -0,0 +1,181 @@
+use std::fmt;
+
+use crate::explain::Explain;
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day3;
+
+/// An instruction of the program in memory. A new instruction needs a variant, its syntax
+/// in [`instruction_at`] and its effect in [`Machine::execute`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    /// `mul(X,Y)`, with numbers of 1 to 3 digits
+    Mul(u32, u32),
+    /// `do()`
+    Do,
+    /// `don't()`
+    Dont,
+}
+
+/// The instruction as written in memory.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Mul(x, y) => write!(f, "mul({},{})", x, y),
+            Instruction::Do => write!(f, "do()"),
+            Instruction::Dont => write!(f, "don't()"),
+        }
+    }
+}
+
+/// An instruction found in memory, at the byte `offset` of its first character.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token {
+    pub offset: usize,
+    pub instruction: Instruction,
+}
+
+/// The instructions of `memory` in order, skipping the corrupted text around them.
+pub fn tokenize(memory: &str) -> Lexer<'_> {
+    Lexer { memory, offset: 0 }
+}
+
+/// Iterator over the [`Token`]s of memory, returned by [`tokenize`].
+pub struct Lexer<'a> {
+    memory: &'a str,
+    offset: usize,
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        while self.offset < self.memory.len() {
+            let offset = self.offset;
+            match instruction_at(&self.memory.as_bytes()[offset..]) {
+                Some((instruction, length)) => {
+                    self.offset += length;
+                    return Some(Token {
+                        offset,
+                        instruction,
+                    });
+                }
+                None => self.offset += 1,
+            }
+        }
+        None
+    }
+}
+
+/// The instruction at the start of `memory` and its length in bytes.
+fn instruction_at(memory: &[u8]) -> Option<(Instruction, usize)> {
+    if let Some(rest) = memory.strip_prefix(b"mul(") {
+        let (x, x_digits) = number(rest)?;
+        let rest = rest[x_digits..].strip_prefix(b",")?;
+        let (y, y_digits) = number(rest)?;
+        rest[y_digits..].strip_prefix(b")")?;
+        Some((Instruction::Mul(x, y), 6 + x_digits + y_digits))
+    } else if memory.starts_with(b"do()") {
+        Some((Instruction::Do, 4))
+    } else if memory.starts_with(b"don't()") {
+        Some((Instruction::Dont, 7))
+    } else {
+        None
+    }
+}
+
+/// The number of 1 to 3 digits at the start of `memory` and its number of digits.
+fn number(memory: &[u8]) -> Option<(u32, usize)> {
+    let digits = memory
+        .iter()
+        .take(4)
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    let value = memory[..digits]
+        .iter()
+        .fold(0, |value, c| value * 10 + u32::from(c - b'0'));
+    (1..=3).contains(&digits).then_some((value, digits))
+}
+
+/// The machine running the instructions, `mul` adding its product to the sum while the
+/// machine is enabled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Machine {
+    /// Whether `do()` and `don't()` switch the machine on and off, or are ignored
+    pub conditional: bool,
+    pub enabled: bool,
+    pub sum: u64,
+}
+
+impl Machine {
+    pub fn new(conditional: bool) -> Self {
+        Machine {
+            conditional,
+            enabled: true,
+            sum: 0,
+        }
+    }
+
+    pub fn execute(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Mul(x, y) if self.enabled => self.sum += u64::from(x * y),
+            Instruction::Mul(..) => {}
+            Instruction::Do if self.conditional => self.enabled = true,
+            Instruction::Dont if self.conditional => self.enabled = false,
+            Instruction::Do | Instruction::Dont => {}
+        }
+    }
+
+    /// Executes every instruction of `tokens` in turn.
+    pub fn run(mut self, tokens: &[Token]) -> Self {
+        for token in tokens {
+            self.execute(token.instruction);
+        }
+        self
+    }
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Token>;
+
+    // Corrupted memory is corrupted by definition: any text is a valid input
+    fn parse(&self, input: &str) -> Result<Vec<Token>, ParseError> {
+        Ok(tokenize(input).collect())
+    }
+
+    fn part1(&self, tokens: &Vec<Token>) -> Answer {
+        Machine::new(false).run(tokens).sum.into()
+    }
+
+    fn part2(&self, tokens: &Vec<Token>) -> Answer {
+        Machine::new(true).run(tokens).sum.into()
+    }
+}
+
+/// One line per instruction with its offset and the sums of both parts so far.
+impl Explain for Day3 {
+    fn explain(&self, tokens: &Vec<Token>) -> Vec<String> {
+        let (mut all, mut conditional) = (Machine::new(false), Machine::new(true));
+        tokens
+            .iter()
+            .map(|token| {
+                all.execute(token.instruction);
+                conditional.execute(token.instruction);
+                format!(
+                    "offset {}: {:<15} part 1 {}, part 2 {}{}",
+                    token.offset,
+                    token.instruction.to_string(),
+                    all.sum,
+                    conditional.sum,
+                    if conditional.enabled {
+                        ""
+                    } else {
+                        " (disabled)"
+                    }
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,78 @@
+use crate::geometry::{Direction8, Vec2};
+use crate::grid::Grid;
+use crate::parse::Source;
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    // Any letter can appear in the word search
+    fn parse(&self, input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(&Source::new(Self::DAY, input), input, Some, "a letter")
+    }
+
+    fn part1(&self, matrix: &Grid<char>) -> Answer {
+        part1(matrix).into()
+    }
+
+    fn part2(&self, matrix: &Grid<char>) -> Answer {
+        part2(matrix).into()
+    }
+}
+
+fn part1(matrix: &Grid<char>) -> u32 {
+    matrix
+        .iter()
+        .filter(|(_, c)| **c == 'X')
+        .map(|(coordinate, _)| count_xmas_word(coordinate, matrix))
+        .sum()
+}
+
+fn part2(matrix: &Grid<char>) -> u32 {
+    matrix
+        .iter()
+        .filter(|(_, c)| **c == 'A')
+        .filter(|(coordinate, _)| {
+            right_diagonal(*coordinate, matrix) && left_diagonal(*coordinate, matrix)
+        })
+        .count() as u32
+}
+
+fn right_diagonal(coordinate: Vec2, matrix: &Grid<char>) -> bool {
+    let (diagonal_up_right, diagonal_down_left) = (
+        matrix.get(coordinate + Direction8::UpRight.offset()),
+        matrix.get(coordinate + Direction8::DownLeft.offset()),
+    );
+
+    is_m_and_s(diagonal_up_right, diagonal_down_left)
+}
+
+fn left_diagonal(coordinate: Vec2, matrix: &Grid<char>) -> bool {
+    let (diagonal_up_left, diagonal_down_right) = (
+        matrix.get(coordinate + Direction8::UpLeft.offset()),
+        matrix.get(coordinate + Direction8::DownRight.offset()),
+    );
+
+    is_m_and_s(diagonal_up_left, diagonal_down_right)
+}
+
+// Both ends of a diagonal of the X, in any order
+fn is_m_and_s(a: Option<&char>, b: Option<&char>) -> bool {
+    matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')))
+}
+
+fn count_xmas_word(coordinate: Vec2, matrix: &Grid<char>) -> u32 {
+    // Horizontal, vertical and diagonal directions
+    Direction8::ALL
+        .iter()
+        .filter(|direction| {
+            "MAS".chars().enumerate().all(|(distance, letter)| {
+                matrix.get(coordinate + direction.offset() * (distance as i64 + 1)) == Some(&letter)
+            })
+        })
+        .count() as u32
+}
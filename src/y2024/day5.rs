@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use crate::parse::{lines, sections, Source};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day5;
+
+pub struct SafetyManual {
+    /// Pairs of pages, the first to be printed before the second
+    rules_set: HashSet<(u32, u32)>,
+    updates: Vec<Vec<u32>>,
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = SafetyManual;
+
+    fn parse(&self, input: &str) -> Result<SafetyManual, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        let mut content = sections(input);
+        let raw_rules = source.next(&mut content, input, "the page ordering rules")?;
+        let raw_updates = source.next(
+            &mut content,
+            raw_rules,
+            "an empty line between rules and updates",
+        )?;
+
+        let mut rules_set: HashSet<(u32, u32)> = HashSet::new();
+        for rule in lines(raw_rules) {
+            let (before, after) = rule
+                .split_once('|')
+                .ok_or_else(|| source.error(rule, "expected a rule like `47|53`"))?;
+            rules_set.insert((source.number(before)?, source.number(after)?));
+        }
+
+        let updates: Vec<Vec<u32>> = lines(raw_updates)
+            .map(|update| {
+                update
+                    .split(',')
+                    .map(|page| source.number(page))
+                    .collect::<Result<_, _>>()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(SafetyManual { rules_set, updates })
+    }
+
+    fn part1(&self, manual: &SafetyManual) -> Answer {
+        part1(&manual.updates, &manual.rules_set).into()
+    }
+
+    fn part2(&self, manual: &SafetyManual) -> Answer {
+        part2(&manual.updates, &manual.rules_set).into()
+    }
+}
+
+fn part1(updates: &[Vec<u32>], rules_set: &HashSet<(u32, u32)>) -> u32 {
+    updates
+        .iter()
+        .filter(|v| is_valid_update(v, rules_set))
+        .map(|v| get_middle_page_number(v))
+        .sum()
+}
+
+fn part2(updates: &[Vec<u32>], rules_set: &HashSet<(u32, u32)>) -> u32 {
+    updates
+        .iter()
+        .filter(|v| !is_valid_update(v, rules_set))
+        .map(|v| transform_sequence(v.clone(), rules_set))
+        .map(|v| get_middle_page_number(&v))
+        .sum()
+}
+
+fn is_valid_update(update: &[u32], rules_set: &HashSet<(u32, u32)>) -> bool {
+    update.iter().enumerate().all(|(i, e)| {
+        let (_, subsequence) = update.split_at(i + 1);
+        is_subsequence_valid(e, subsequence, rules_set)
+    })
+}
+
+fn is_subsequence_valid(head: &u32, subsequence: &[u32], rules_set: &HashSet<(u32, u32)>) -> bool {
+    subsequence.iter().all(|e| rules_set.contains(&(*head, *e)))
+}
+
+fn get_middle_page_number(update: &[u32]) -> u32 {
+    update[update.len() / 2]
+}
+
+fn transform_sequence(update: Vec<u32>, rules_set: &HashSet<(u32, u32)>) -> Vec<u32> {
+    let mut result = update;
+
+    // Sort the sequence according to the rules
+    result.sort_by(|a, b| {
+        if rules_set.contains(&(*a, *b)) {
+            std::cmp::Ordering::Less
+        } else if rules_set.contains(&(*b, *a)) {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    });
+
+    result
+}
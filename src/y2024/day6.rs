@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+
+use rayon::prelude::*;
+
+use crate::geometry::{Direction4, Vec2};
+use crate::grid::Grid;
+use crate::parse::{lines, Source};
+use crate::visualize::{Color, Frame, Tile, Visualize};
+use crate::{Answer, ParseError, Solution};
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+enum Status {
+    Free,
+    Obstructed,
+}
+
+#[derive(Clone, Debug)]
+struct Cell {
+    coordinate: Vec2,
+    status: Status,
+}
+
+#[derive(Clone, Debug, Copy)]
+struct Guard {
+    coordinate: Vec2,
+    direction: Direction4,
+}
+
+impl Guard {
+    fn new(coordinate: Vec2, character: char) -> Option<Self> {
+        Some(Self {
+            coordinate,
+            direction: Direction4::from_char(character)?,
+        })
+    }
+
+    fn with_cell(self, cell: Cell) -> Self {
+        Self {
+            coordinate: cell.coordinate,
+            direction: self.direction,
+        }
+    }
+
+    fn with_new_direction(self) -> Self {
+        Self {
+            direction: self.direction.turn_right(),
+            coordinate: self.coordinate,
+        }
+    }
+}
+
+pub struct Day6;
+
+pub struct Lab {
+    map: Grid<Status>,
+    guard: Guard,
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Lab;
+
+    fn parse(&self, input: &str) -> Result<Lab, ParseError> {
+        let (map, guard) = create_map_and_guard(Source::new(Self::DAY, input))?;
+        Ok(Lab { map, guard })
+    }
+
+    fn part1(&self, lab: &Lab) -> Answer {
+        part1(lab).len().into()
+    }
+
+    fn part2(&self, lab: &Lab) -> Answer {
+        let visited_positions = part1(lab);
+        loop_obstacles(lab, &visited_positions).len().into()
+    }
+}
+
+/// The guard walking step by step, then the obstructions that would trap it in a loop.
+impl Visualize for Day6 {
+    fn frames<'a>(&'a self, lab: &'a Lab) -> Box<dyn Iterator<Item = Frame> + 'a> {
+        let picture = |visited: &HashSet<Vec2>| {
+            Grid::from_fn(lab.map.width(), lab.map.height(), |c| match lab.map[c] {
+                Status::Obstructed => Tile::colored('#', Color::Gray),
+                Status::Free if visited.contains(&c) => Tile::colored('X', Color::Yellow),
+                Status::Free => Tile::plain('.'),
+            })
+        };
+
+        let guards = std::iter::successors(Some(lab.guard), |guard| {
+            get_next_cell(guard, &lab.map).map(|next_cell| move_guard(*guard, next_cell))
+        });
+        let mut visited = HashSet::new();
+        let walk = guards.enumerate().map(move |(step, guard)| {
+            visited.insert(guard.coordinate);
+            let mut grid = picture(&visited);
+            grid[guard.coordinate] = Tile::colored(guard.direction.to_char(), Color::Red);
+            Frame::new(
+                format!("step {}, {} positions visited", step, visited.len()),
+                grid,
+            )
+        });
+
+        let obstacles = std::iter::once_with(move || {
+            let visited = part1(lab);
+            let obstacles = loop_obstacles(lab, &visited);
+            let mut grid = picture(&visited);
+            for &obstacle in &obstacles {
+                grid[obstacle] = Tile::colored('O', Color::Magenta);
+            }
+            grid[lab.guard.coordinate] = Tile::colored(lab.guard.direction.to_char(), Color::Red);
+            Frame::new(
+                format!("{} obstructions causing a loop", obstacles.len()),
+                grid,
+            )
+        });
+
+        Box::new(walk.chain(obstacles))
+    }
+}
+
+fn part1(lab: &Lab) -> HashSet<Vec2> {
+    let mut visited_positions: HashSet<Vec2> = HashSet::new();
+
+    let (map, mut guard) = (&lab.map, lab.guard);
+    visited_positions.insert(guard.coordinate);
+
+    while let Some(next_cell) = get_next_cell(&guard, map) {
+        visited_positions.insert(guard.coordinate);
+        guard = move_guard(guard, next_cell);
+    }
+
+    visited_positions.insert(guard.coordinate);
+    visited_positions
+}
+
+/// Positions of the path where a new obstruction would trap the guard in a loop.
+fn loop_obstacles(lab: &Lab, visited_positions: &HashSet<Vec2>) -> Vec<Vec2> {
+    let (map, initial_guard) = (&lab.map, lab.guard);
+    let initial_coordinate = initial_guard.coordinate;
+
+    visited_positions
+        .par_iter()
+        .filter(|coord| **coord != initial_coordinate)
+        .filter(|coord| {
+            let mut modified_map = map.clone();
+            modified_map[**coord] = Status::Obstructed;
+
+            is_infinite_loop(&modified_map, initial_guard)
+        })
+        .copied()
+        .collect()
+}
+
+fn is_infinite_loop(map: &Grid<Status>, initial_guard: Guard) -> bool {
+    let max_iterations = 10_000; // Dirty and faster workaround
+    let mut current_guard = initial_guard;
+
+    for _ in 0..max_iterations {
+        match get_next_cell(&current_guard, map) {
+            Some(next_cell) => {
+                current_guard = move_guard(current_guard, next_cell);
+            }
+            None => return false,
+        }
+    }
+    true
+}
+
+fn create_map_and_guard(source: Source) -> Result<(Grid<Status>, Guard), ParseError> {
+    let text = source.text();
+    let map = Grid::parse(
+        &source,
+        text,
+        get_map_status,
+        "`.`, `#` or a guard (`^>v<`)",
+    )?;
+
+    let mut guards = lines(text).enumerate().flat_map(|(line_index, line)| {
+        line.char_indices()
+            .enumerate()
+            .filter_map(move |(column_index, (offset, character))| {
+                let coordinate = Vec2::new(line_index as i64, column_index as i64);
+                Guard::new(coordinate, character).map(|guard| (guard, line, offset))
+            })
+    });
+    let (guard, _, _) = guards
+        .next()
+        .ok_or_else(|| source.missing(text, "a guard on the map"))?;
+    if let Some((_, line, offset)) = guards.next() {
+        return Err(source.unexpected_char(line, offset, "a single guard"));
+    }
+
+    Ok((map, guard))
+}
+
+fn get_map_status(character: char) -> Option<Status> {
+    match character {
+        '#' => Some(Status::Obstructed),
+        '.' | '^' | '>' | 'v' | '<' => Some(Status::Free),
+        _ => None,
+    }
+}
+
+fn get_next_cell(guard: &Guard, map: &Grid<Status>) -> Option<Cell> {
+    let coordinate_to_check = guard.coordinate + guard.direction.offset();
+
+    map.get(coordinate_to_check).map(|status| Cell {
+        coordinate: coordinate_to_check,
+        status: *status,
+    })
+}
+
+fn move_guard(guard: Guard, next_cell: Cell) -> Guard {
+    match next_cell.status {
+        Status::Free => guard.with_cell(next_cell),
+        Status::Obstructed => guard.with_new_direction(),
+    }
+}
@@ -0,0 +1,88 @@
+use rayon::prelude::*;
+
+use crate::parse::{lines, Source};
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    fn parse(&self, input: &str) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        lines(input)
+            .map(|line| {
+                let mut parts = line.split(':').map(str::trim);
+                let expected_result = source.number(source.next(&mut parts, line, "a result")?)?;
+                let numbers: Vec<u64> = source
+                    .next(&mut parts, line, "`:` followed by the numbers")?
+                    .split_whitespace()
+                    .map(|n| source.number(n))
+                    .collect::<Result<_, _>>()?;
+                if numbers.is_empty() {
+                    return Err(source.missing(line, "at least one number"));
+                }
+
+                Ok((expected_result, numbers))
+            })
+            .collect()
+    }
+
+    fn part1(&self, lines: &Vec<(u64, Vec<u64>)>) -> Answer {
+        calculate_valid_equations(lines, ["+", "*"].to_vec()).into()
+    }
+
+    fn part2(&self, lines: &Vec<(u64, Vec<u64>)>) -> Answer {
+        calculate_valid_equations(lines, ["+", "*", "||"].to_vec()).into()
+    }
+}
+
+fn calculate_valid_equations(lines: &[(u64, Vec<u64>)], operators: Vec<&str>) -> u64 {
+    lines
+        .par_iter()
+        .filter(|(expected, numbers)| {
+            is_equation_valid(*expected, &numbers[1..], &operators, numbers[0])
+        })
+        .map(|(result, _)| result)
+        .sum()
+}
+
+fn is_equation_valid(
+    expected_result: u64,
+    numbers: &[u64],
+    operators: &[&str],
+    partial_result: u64,
+) -> bool {
+    if partial_result > expected_result {
+        return false;
+    }
+
+    if numbers.is_empty() {
+        return partial_result == expected_result;
+    }
+
+    operators.iter().any(|&op| {
+        let next_partial = match op {
+            "+" => add(partial_result, numbers[0]),
+            "*" => multiply(partial_result, numbers[0]),
+            "||" => concat(partial_result, numbers[0]),
+            _ => panic!("Unknown operator"),
+        };
+
+        is_equation_valid(expected_result, &numbers[1..], operators, next_partial)
+    })
+}
+
+fn add(a: u64, b: u64) -> u64 {
+    a + b
+}
+
+fn multiply(a: u64, b: u64) -> u64 {
+    a * b
+}
+
+fn concat(a: u64, b: u64) -> u64 {
+    format!("{}{}", a, b).parse::<u64>().unwrap()
+}
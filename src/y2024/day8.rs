@@ -0,0 +1,127 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::geometry::Vec2;
+use crate::grid::Grid;
+use crate::parse::Source;
+use crate::{Answer, ParseError, Solution};
+
+enum Direction {
+    Forward,
+    Backward,
+}
+
+pub struct Day8;
+
+pub struct AntennaMap {
+    map: Grid<char>,
+    antennas: HashMap<char, Vec<Vec2>>,
+}
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = AntennaMap;
+
+    fn parse(&self, input: &str) -> Result<AntennaMap, ParseError> {
+        // Any character other than `.` is the frequency of an antenna
+        let map = Grid::parse(&Source::new(Self::DAY, input), input, Some, "a frequency")?;
+
+        let mut antennas: HashMap<char, Vec<Vec2>> = HashMap::new();
+
+        for (coord, &character) in map.iter() {
+            if character != '.' {
+                antennas
+                    .entry(character)
+                    .and_modify(|v| v.push(coord))
+                    .or_insert(vec![coord]);
+            }
+        }
+
+        Ok(AntennaMap { map, antennas })
+    }
+
+    fn part1(&self, map: &AntennaMap) -> Answer {
+        part1(&map.antennas, &map.map).len().into()
+    }
+
+    fn part2(&self, map: &AntennaMap) -> Answer {
+        part2(&map.antennas, &map.map).len().into()
+    }
+}
+
+fn part1(antennas: &HashMap<char, Vec<Vec2>>, grid: &Grid<char>) -> HashSet<Vec2> {
+    let mut antinodes: HashSet<Vec2> = HashSet::new();
+    for antenna_type in antennas.iter() {
+        let antenna_type_coordinates = antenna_type.1;
+
+        for (index, i) in antenna_type_coordinates.iter().enumerate() {
+            for j in antenna_type_coordinates[index + 1..].iter() {
+                let (antenna1, antenna2) = (*i, *j);
+
+                let diff = antenna2 - antenna1;
+                get_next_antinode(antenna1, diff, grid, Direction::Backward)
+                    .and_then(|c| antinodes.insert(c).then_some(()));
+                get_next_antinode(antenna2, diff, grid, Direction::Forward)
+                    .and_then(|c| antinodes.insert(c).then_some(()));
+            }
+        }
+    }
+
+    antinodes
+}
+
+fn part2(antennas: &HashMap<char, Vec<Vec2>>, grid: &Grid<char>) -> HashSet<Vec2> {
+    let mut antinodes: HashSet<Vec2> = HashSet::new();
+
+    for antenna_type in antennas.iter() {
+        let antenna_type_coordinates = antenna_type.1;
+
+        for (index, i) in antenna_type_coordinates.iter().enumerate() {
+            for j in antenna_type_coordinates[index + 1..].iter() {
+                let (antenna1, antenna2) = (*i, *j);
+                let diff = antenna2 - antenna1;
+
+                antinodes.insert(antenna1);
+                antinodes.insert(antenna2);
+
+                let mut iter_coord: Vec2 = antenna1;
+
+                while let Some(coord) =
+                    get_next_antinode(iter_coord, diff, grid, Direction::Backward)
+                {
+                    antinodes.insert(coord);
+                    iter_coord = coord;
+                }
+
+                iter_coord = antenna2;
+
+                while let Some(coord) =
+                    get_next_antinode(iter_coord, diff, grid, Direction::Forward)
+                {
+                    antinodes.insert(coord);
+                    iter_coord = coord;
+                }
+            }
+        }
+    }
+
+    antinodes
+}
+
+fn get_next_antinode(
+    coord: Vec2,
+    diff: Vec2,
+    grid: &Grid<char>,
+    direction: Direction,
+) -> Option<Vec2> {
+    match direction {
+        Direction::Forward => {
+            let new_coord = coord + diff;
+            grid.contains(new_coord).then_some(new_coord)
+        }
+        Direction::Backward => {
+            let new_coord = coord - diff;
+            grid.contains(new_coord).then_some(new_coord)
+        }
+    }
+}
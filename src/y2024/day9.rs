@@ -0,0 +1,88 @@
+use crate::parse::Source;
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    /// The lengths of the files and of the free space between them, in turn
+    type Input = Vec<u8>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u8>, ParseError> {
+        let source = Source::new(Self::DAY, input);
+        input
+            .char_indices()
+            .map(|(offset, c)| {
+                c.to_digit(10)
+                    .map(|length| length as u8)
+                    .ok_or_else(|| source.unexpected_char(input, offset, "a digit"))
+            })
+            .collect()
+    }
+
+    fn part1(&self, disk_map: &Vec<u8>) -> Answer {
+        part1(&mut blocks(disk_map).into_iter().flatten().collect::<Vec<_>>()).into()
+    }
+
+    fn part2(&self, disk_map: &Vec<u8>) -> Answer {
+        part2(&mut blocks(disk_map)).into()
+    }
+}
+
+/// The blocks of each file and of each free space of the disk map, with the ID of the file
+/// they belong to, skipping empty ones.
+fn blocks(disk_map: &[u8]) -> Vec<Vec<Option<u64>>> {
+    disk_map
+        .iter()
+        .enumerate()
+        .filter(|(_, length)| **length > 0)
+        .map(|(index, &length)| {
+            let file = (index % 2 == 0).then_some(index as u64 / 2);
+            vec![file; usize::from(length)]
+        })
+        .collect()
+}
+
+fn part1(disk_map: &mut [Option<u64>]) -> u64 {
+    let disk_map_length = disk_map.len();
+    for i in 0..disk_map_length {
+        if disk_map[i].is_none() {
+            for j in (i + 1..disk_map_length).rev() {
+                if disk_map[j].is_some() {
+                    disk_map.swap(i, j);
+                    break;
+                }
+            }
+        }
+    }
+
+    checksum(disk_map.iter())
+}
+
+fn part2(disk_map: &mut [Vec<Option<u64>>]) -> u64 {
+    // Free blocks are always at the end of a space, the files moved in filling it from the start
+    let free = |blocks: &[Option<u64>]| blocks.iter().filter(|b| b.is_none()).count();
+
+    for i in (0..disk_map.len()).rev() {
+        if disk_map[i].contains(&None) {
+            continue;
+        }
+        let space_needed = disk_map[i].len();
+        if let Some(j) = (0..i).find(|&j| free(&disk_map[j]) >= space_needed) {
+            let first_free_index = disk_map[j].len() - free(&disk_map[j]);
+            for index in 0..space_needed {
+                disk_map[j][first_free_index + index] = disk_map[i][index].take();
+            }
+        }
+    }
+
+    checksum(disk_map.iter().flatten())
+}
+
+fn checksum<'a>(blocks: impl Iterator<Item = &'a Option<u64>>) -> u64 {
+    blocks
+        .enumerate()
+        .filter_map(|(i, file)| file.map(|id| i as u64 * id))
+        .sum()
+}
@@ -0,0 +1,98 @@
+//! The puzzles of 2024.
+
+use crate::explain::Explainer;
+use crate::reference;
+use crate::visualize::Visualizer;
+use crate::{Params, Solver};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub fn get_solver(day: u8, params: &Params) -> Option<Box<dyn Solver>> {
+    let solver: Box<dyn Solver> = match day {
+        1 => Box::new(day1::Day1::new(params)),
+        2 => Box::new(day2::Day2::new(params)),
+        3 => Box::new(day3::Day3),
+        4 => Box::new(day4::Day4),
+        5 => Box::new(day5::Day5),
+        6 => Box::new(day6::Day6),
+        7 => Box::new(day7::Day7),
+        8 => Box::new(day8::Day8),
+        9 => Box::new(day9::Day9),
+        10 => Box::new(day10::Day10),
+        11 => Box::new(day11::Day11::new(params)),
+        12 => Box::new(day12::Day12),
+        13 => Box::new(day13::Day13),
+        14 => Box::new(day14::Day14::new(params)),
+        15 => Box::new(day15::Day15),
+        16 => Box::new(day16::Day16),
+        17 => Box::new(day17::Day17),
+        18 => Box::new(day18::Day18::new(params)),
+        19 => Box::new(day19::Day19),
+        20 => Box::new(day20::Day20::new(params)),
+        21 => Box::new(day21::Day21),
+        22 => Box::new(day22::Day22),
+        23 => Box::new(day23::Day23),
+        24 => Box::new(day24::Day24),
+        25 => Box::new(day25::Day25),
+        _ => return None,
+    };
+    Some(solver)
+}
+
+pub fn get_visualizer(day: u8, params: &Params) -> Option<Box<dyn Visualizer>> {
+    let visualizer: Box<dyn Visualizer> = match day {
+        6 => Box::new(day6::Day6),
+        12 => Box::new(day12::Day12),
+        14 => Box::new(day14::Day14::new(params)),
+        15 => Box::new(day15::Day15),
+        16 => Box::new(day16::Day16),
+        18 => Box::new(day18::Day18::new(params)),
+        20 => Box::new(day20::Day20::new(params)),
+        _ => return None,
+    };
+    Some(visualizer)
+}
+
+pub fn get_explainer(day: u8, params: &Params) -> Option<Box<dyn Explainer>> {
+    let explainer: Box<dyn Explainer> = match day {
+        2 => Box::new(day2::Day2::new(params)),
+        3 => Box::new(day3::Day3),
+        _ => return None,
+    };
+    Some(explainer)
+}
+
+pub fn get_reference(day: u8, params: &Params) -> Option<Box<dyn Solver>> {
+    let reference: Box<dyn Solver> = match day {
+        11 => Box::new(reference::Day11::new(params)),
+        13 => Box::new(reference::Day13),
+        17 => Box::new(reference::Day17),
+        20 => Box::new(reference::Day20::new(params)),
+        _ => return None,
+    };
+    Some(reference)
+}
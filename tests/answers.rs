@@ -0,0 +1,89 @@
+use std::path::Path;
+
+use aoc::answers::{Answers, Verdict, EXAMPLE_ANSWERS_FILE};
+use aoc::input::example_path;
+use aoc::{Answer, Part, Puzzle};
+
+const ANSWERS: &str = r#"
+[2024.day17]
+part1 = "5,7,3,0"
+part2 = 117440
+
+[2024.day3]
+part1 = 161
+
+[2023.day3]
+part1 = 4361
+"#;
+
+fn puzzle(day: u8) -> Puzzle {
+    Puzzle::new(2024, day)
+}
+
+#[test]
+fn reads_numbers_and_text() {
+    let answers = Answers::parse(ANSWERS).unwrap();
+
+    assert_eq!(
+        answers.expected(puzzle(17), Part::One),
+        Some(Answer::Text("5,7,3,0".to_string()))
+    );
+    assert_eq!(
+        answers.expected(puzzle(17), Part::Two),
+        Some(Answer::Number(117440))
+    );
+    assert_eq!(answers.expected(puzzle(3), Part::Two), None);
+    assert_eq!(answers.expected(puzzle(4), Part::One), None);
+    assert_eq!(
+        answers.expected(Puzzle::new(2023, 3), Part::One),
+        Some(Answer::Number(4361))
+    );
+    assert_eq!(answers.expected(Puzzle::new(2023, 17), Part::One), None);
+}
+
+#[test]
+fn checks_answers() {
+    let answers = Answers::parse(ANSWERS).unwrap();
+
+    assert_eq!(
+        answers.check(puzzle(3), Part::One, &Answer::Number(161)),
+        Verdict::Match
+    );
+    assert_eq!(
+        answers.check(puzzle(3), Part::One, &Answer::Number(160)),
+        Verdict::Mismatch(Answer::Number(161))
+    );
+    // A number never matches the same digits given as text
+    assert_eq!(
+        answers.check(puzzle(3), Part::One, &Answer::Text("161".to_string())),
+        Verdict::Mismatch(Answer::Number(161))
+    );
+    assert_eq!(
+        answers.check(puzzle(3), Part::Two, &Answer::Number(48)),
+        Verdict::Missing
+    );
+}
+
+#[test]
+fn rejects_unknown_years_days_and_fields() {
+    assert!(Answers::parse("[2024.day26]\npart1 = 1").is_err());
+    assert!(Answers::parse("[2024.dec1]\npart1 = 1").is_err());
+    assert!(Answers::parse("[2024.day1]\npart3 = 1").is_err());
+    assert!(Answers::parse("[24.day1]\npart1 = 1").is_err());
+    // Tables of days without a year
+    assert!(Answers::parse("[day1]\npart1 = 1").is_err());
+}
+
+#[test]
+fn example_answers_cover_every_example() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let answers = Answers::load(&root.join(EXAMPLE_ANSWERS_FILE)).unwrap();
+
+    for puzzle in aoc::puzzles() {
+        // A day just started has an empty example, waiting to be filled in
+        let example = std::fs::read_to_string(root.join(example_path(puzzle))).unwrap();
+        if !example.trim().is_empty() {
+            assert!(answers.expected(puzzle, Part::One).is_some(), "{}", puzzle);
+        }
+    }
+}
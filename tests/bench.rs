@@ -0,0 +1,57 @@
+use std::path::Path;
+use std::time::Duration;
+
+use aoc::bench::{bench_day, Stats};
+use aoc::input::example_path;
+use aoc::{Mode, Params, Part, Puzzle};
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn stats_of_an_odd_number_of_runs() {
+    assert_eq!(
+        Stats::new(&[ms(5), ms(1), ms(3)]),
+        Some(Stats {
+            min: ms(1),
+            median: ms(3),
+            max: ms(5),
+        })
+    );
+}
+
+#[test]
+fn stats_of_an_even_number_of_runs() {
+    assert_eq!(
+        Stats::new(&[ms(8), ms(2), ms(4), ms(1)]),
+        Some(Stats {
+            min: ms(1),
+            median: ms(3),
+            max: ms(8),
+        })
+    );
+}
+
+#[test]
+fn stats_without_runs() {
+    assert_eq!(Stats::new(&[]), None);
+}
+
+#[test]
+fn benches_the_selected_parts() {
+    let puzzle = Puzzle::new(2024, 1);
+    let input = Path::new(env!("CARGO_MANIFEST_DIR")).join(example_path(puzzle));
+    let params = Params::with_mode(Mode::Example);
+    let bench = bench_day(puzzle, Some(Part::Two), &params, Some(&input), 3).unwrap();
+
+    assert_eq!(bench.puzzle, puzzle);
+    assert_eq!(bench.runs, 3);
+    assert_eq!(
+        bench.parts.iter().map(|(p, _)| *p).collect::<Vec<_>>(),
+        [Part::Two]
+    );
+    for stats in [bench.parse, bench.parts[0].1] {
+        assert!(stats.min <= stats.median && stats.median <= stats.max);
+    }
+}
@@ -0,0 +1,59 @@
+use aoc::{get_solver, Answer, Params, ParseError, Part, Puzzle};
+
+fn solve(input: &str) -> Result<Answer, ParseError> {
+    let solver = get_solver(Puzzle::new(2024, 24), &Params::default()).unwrap();
+    let parsed = solver.parse(input)?;
+    Ok(solver.solve(&*parsed, Part::One))
+}
+
+#[test]
+fn gates_run_in_the_order_of_their_inputs() {
+    let input = "x00: 1\ny00: 1\n\nabc XOR y00 -> z01\nx00 AND y00 -> abc\nx00 OR abc -> z00";
+    assert_eq!(solve(input).unwrap(), Answer::Number(1));
+}
+
+#[test]
+fn every_wire_is_driven_once() {
+    for (gates, line, column, message) in [
+        (
+            "x00 AND q00 -> z00",
+            4,
+            9,
+            "expected a wire with an initial value or driven by a gate",
+        ),
+        (
+            "x00 AND y00 -> z00\nx00 OR y00 -> z00",
+            5,
+            15,
+            "expected a wire driven by a single gate",
+        ),
+        (
+            "x00 AND y00 -> y00",
+            4,
+            16,
+            "expected a wire without an initial value",
+        ),
+        (
+            "x00 AND abc -> abd\nabd OR y00 -> abc\nabc XOR y00 -> z00",
+            4,
+            16,
+            "expected gates without loops, this one never gets its inputs",
+        ),
+        (
+            "x00 AND y00 -> z64",
+            4,
+            16,
+            "expected an output bit below 64, not 64",
+        ),
+        (
+            "x00 AND y00 -> z00 -> z01",
+            4,
+            1,
+            "expected a gate like `x00 AND y00 -> z00`",
+        ),
+    ] {
+        let error = solve(&format!("x00: 1\ny00: 0\n\n{}", gates)).unwrap_err();
+        assert_eq!((error.line, error.column), (line, column), "{}", gates);
+        assert_eq!(error.message, message, "{}", gates);
+    }
+}
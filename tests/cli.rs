@@ -0,0 +1,55 @@
+use std::process::{Command, Output};
+
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn empty_grids_are_rejected() {
+    for day in ["14", "18"] {
+        for (flag, other) in [("--width", "--height"), ("--height", "--width")] {
+            let output = aoc(&["run", "2024", day, "--example", flag, "0", other, "7"]);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            // Exit code 2 is a usage error from the parser, a panic exits with 101
+            assert_eq!(
+                output.status.code(),
+                Some(2),
+                "day {} {}: {}",
+                day,
+                flag,
+                stderr
+            );
+            assert!(stderr.contains(flag), "day {} {}: {}", day, flag, stderr);
+            assert!(
+                !stderr.contains("panicked"),
+                "day {} {}: {}",
+                day,
+                flag,
+                stderr
+            );
+        }
+    }
+}
+
+#[test]
+fn input_directories_hold_one_directory_per_year() {
+    let dir = std::env::temp_dir().join(format!("aoc-cli-inputs-{}", std::process::id()));
+    let year = dir.join("2024");
+    std::fs::create_dir_all(&year).unwrap();
+    std::fs::write(year.join("input-day1.txt"), "3   4\n4   3\n").unwrap();
+
+    let dir_arg = dir.to_str().unwrap();
+    let output = aoc(&["run", "2024", "1", "--format", "json", "--input", dir_arg]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    // Similarity score of part 2
+    assert!(stdout.contains("\"answer\": 7"), "{}", stdout);
+    std::fs::remove_dir_all(dir).unwrap();
+}
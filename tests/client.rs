@@ -0,0 +1,268 @@
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::client::{
+    Client, ClientConfig, ClientError, Feedback, Fetched, Submission, LAST_REQUEST_FILE,
+};
+use aoc::{Answer, Part, Puzzle};
+
+const RIGHT: &str =
+    "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+const TOO_HIGH: &str =
+    "<article><p>That's not the right answer; your answer is too high.</p></article>";
+const WRONG: &str = "<article><p>That's not the right answer.</p></article>";
+const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait after \
+                        submitting an answer before trying again. You have 1m 5s left to \
+                        wait.</p></article>";
+
+/// A request received by the mock site.
+struct Request {
+    line: String,
+    cookie: Option<String>,
+    body: String,
+}
+
+/// A site on a local port answering one request per connection with the next of its
+/// replies, and keeping the requests.
+struct MockSite {
+    url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockSite {
+    fn new(replies: Vec<(u16, &'static str)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (status, body) in replies {
+                let (mut stream, _) = listener.accept().unwrap();
+                let request = read_request(&mut BufReader::new(&stream));
+                received.lock().unwrap().push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        MockSite { url, requests }
+    }
+
+    fn requests(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> Request {
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    let (mut cookie, mut length) = (None, 0);
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(": ").unwrap();
+        match name.to_ascii_lowercase().as_str() {
+            "cookie" => cookie = Some(value.to_string()),
+            "content-length" => length = value.parse().unwrap(),
+            _ => {}
+        }
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+    Request {
+        line: line.trim_end().to_string(),
+        cookie,
+        body: String::from_utf8(body).unwrap(),
+    }
+}
+
+/// An empty input directory of its own.
+fn cache_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn config(site: &MockSite, cache_dir: &Path) -> ClientConfig {
+    ClientConfig {
+        base_url: site.url.clone(),
+        session: "53c237".to_string(),
+        cache_dir: cache_dir.to_path_buf(),
+        min_interval: Duration::ZERO,
+    }
+}
+
+#[test]
+fn downloads_an_input_once() {
+    let site = MockSite::new(vec![(200, "3   4\n4   3\n")]);
+    let dir = cache_dir("once");
+    let mut client = Client::new(config(&site, &dir));
+    let puzzle = Puzzle::new(2024, 1);
+    let path = dir.join("2024").join("input-day1.txt");
+
+    assert_eq!(
+        client.fetch_input(puzzle).unwrap(),
+        Fetched::Downloaded(path.clone())
+    );
+    assert_eq!(
+        client.fetch_input(puzzle).unwrap(),
+        Fetched::Cached(path.clone())
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+
+    let requests = site.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].line, "GET /2024/day/1/input HTTP/1.1");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=53c237"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn base_urls_lose_their_trailing_slash() {
+    let site = MockSite::new(vec![(200, "3   4\n4   3\n")]);
+    let dir = cache_dir("slash");
+    let mut client = Client::new(ClientConfig {
+        cache_dir: dir.clone(),
+        min_interval: Duration::ZERO,
+        ..ClientConfig::new(&format!(" {}/ ", site.url), "53c237\n")
+    });
+
+    client.fetch_input(Puzzle::new(2024, 1)).unwrap();
+
+    let requests = site.requests.lock().unwrap();
+    assert_eq!(requests[0].line, "GET /2024/day/1/input HTTP/1.1");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=53c237"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn never_sends_the_same_guess_twice() {
+    let site = MockSite::new(vec![
+        (200, TOO_HIGH),
+        (200, WRONG),
+        (200, RIGHT),
+        (200, RIGHT),
+    ]);
+    let dir = cache_dir("guesses");
+    let mut client = Client::new(config(&site, &dir));
+    let puzzle = Puzzle::new(2024, 7);
+    let mut submit = |answer| client.submit(puzzle, Part::One, &Answer::Number(answer));
+
+    assert_eq!(submit(500).unwrap(), Submission::Sent(Feedback::TooHigh));
+    assert_eq!(submit(500).unwrap(), Submission::Known(Feedback::TooHigh));
+    assert_eq!(submit(600).unwrap(), Submission::Known(Feedback::TooHigh));
+    assert_eq!(submit(300).unwrap(), Submission::Sent(Feedback::Wrong));
+    assert_eq!(submit(400).unwrap(), Submission::Sent(Feedback::Correct));
+    assert_eq!(submit(450).unwrap(), Submission::Known(Feedback::Wrong));
+    assert_eq!(site.requests(), 3);
+    assert_eq!(
+        site.requests.lock().unwrap()[0].line,
+        "POST /2024/day/7/answer HTTP/1.1"
+    );
+    assert_eq!(site.requests.lock().unwrap()[0].body, "level=1&answer=500");
+
+    // Remembered by the next run too, but only for that part
+    let mut client = Client::new(config(&site, &dir));
+    assert_eq!(
+        client
+            .submit(puzzle, Part::One, &Answer::Number(400))
+            .unwrap(),
+        Submission::Known(Feedback::Correct)
+    );
+    assert_eq!(
+        client
+            .submit(puzzle, Part::Two, &Answer::Number(400))
+            .unwrap(),
+        Submission::Sent(Feedback::Correct)
+    );
+    assert_eq!(site.requests(), 4);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn answers_sent_too_soon_are_not_remembered() {
+    let site = MockSite::new(vec![(200, TOO_SOON), (200, WRONG)]);
+    let dir = cache_dir("soon");
+    let mut client = Client::new(config(&site, &dir));
+    let (puzzle, answer) = (Puzzle::new(2024, 17), Answer::Text("4,6,3".to_string()));
+
+    assert_eq!(
+        client.submit(puzzle, Part::One, &answer).unwrap(),
+        Submission::TooSoon(Duration::from_secs(65))
+    );
+    assert_eq!(
+        client.submit(puzzle, Part::One, &answer).unwrap(),
+        Submission::Sent(Feedback::Wrong)
+    );
+    assert_eq!(
+        site.requests.lock().unwrap()[1].body,
+        "level=1&answer=4%2C6%2C3"
+    );
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn spaces_out_requests() {
+    let site = MockSite::new(vec![(200, "1"), (200, "2")]);
+    let dir = cache_dir("spaced");
+    let mut client = Client::new(ClientConfig {
+        min_interval: Duration::from_millis(200),
+        ..config(&site, &dir)
+    });
+
+    // Timed here rather than on the site, which may read the first request late
+    let start = Instant::now();
+    client.fetch_input(Puzzle::new(2024, 1)).unwrap();
+    client.fetch_input(Puzzle::new(2024, 2)).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(200));
+    assert_eq!(site.requests(), 2);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn spaces_out_requests_across_runs() {
+    let site = MockSite::new(vec![(200, "1"), (200, "2")]);
+    let dir = cache_dir("spaced-runs");
+    let config = ClientConfig {
+        min_interval: Duration::from_millis(200),
+        ..config(&site, &dir)
+    };
+
+    let start = Instant::now();
+    Client::new(config.clone())
+        .fetch_input(Puzzle::new(2023, 1))
+        .unwrap();
+    assert!(dir.join(LAST_REQUEST_FILE).exists());
+    Client::new(config)
+        .fetch_input(Puzzle::new(2024, 1))
+        .unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(200));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn refused_sessions_leave_no_input() {
+    let site = MockSite::new(vec![(400, "Puzzle inputs differ by user.  Please log in.")]);
+    let dir = cache_dir("refused");
+    let mut client = Client::new(config(&site, &dir));
+
+    let error = client.fetch_input(Puzzle::new(2024, 3)).unwrap_err();
+    assert!(matches!(error, ClientError::Status { status: 400, .. }));
+    assert!(error.to_string().contains("AOC_SESSION"));
+    assert!(!dir.join("2024").join("input-day3.txt").exists());
+    let _ = fs::remove_dir_all(dir);
+}
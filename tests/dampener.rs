@@ -0,0 +1,232 @@
+use aoc::y2024::day2::{check, diagnose, Diagnosis, InvertedGaps, Policy, Problem, Violation};
+use aoc::{get_solver, Answer, Params, Part, Puzzle, Trend};
+
+fn removed(levels: &[u32], policy: &Policy) -> Option<Vec<usize>> {
+    match diagnose(levels, policy) {
+        Diagnosis::Safe => Some(Vec::new()),
+        Diagnosis::Dampened { removed, .. } => Some(removed),
+        Diagnosis::Unsafe(_) => None,
+    }
+}
+
+/// Every set of at most `policy.removals` levels, fewest first then earliest first, until
+/// one leaves a safe report.
+fn brute_force(levels: &[u32], policy: &Policy) -> Option<Vec<usize>> {
+    let n = levels.len();
+    let mut sets: Vec<Vec<usize>> = (0..1u32 << n)
+        .map(|mask| (0..n).filter(|i| mask & (1 << i) != 0).collect())
+        .filter(|set: &Vec<usize>| set.len() <= policy.removals)
+        .collect();
+    sets.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+    sets.into_iter().find(|set| {
+        let kept: Vec<u32> = (0..n)
+            .filter(|i| !set.contains(i))
+            .map(|i| levels[i])
+            .collect();
+        check(&kept, policy).is_ok()
+    })
+}
+
+#[test]
+fn dampens_like_trying_every_removal() {
+    let mut seed: u64 = 0x2024;
+    let mut random = |bound: u64| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) % bound
+    };
+
+    for _ in 0..3000 {
+        let policy = Policy {
+            gaps: random(2) as u32..=(1 + random(3) as u32),
+            trend: [Trend::Increasing, Trend::Decreasing, Trend::Either][random(3) as usize],
+            removals: random(4) as usize,
+        };
+        let mut level = 20 + random(10) as i64;
+        let levels: Vec<u32> = (0..random(10))
+            .map(|_| {
+                level += random(9) as i64 - 4;
+                level.max(0) as u32
+            })
+            .collect();
+
+        assert_eq!(
+            removed(&levels, &policy),
+            brute_force(&levels, &policy),
+            "{:?} {:?}",
+            levels,
+            policy
+        );
+    }
+}
+
+#[test]
+fn follows_the_policy() {
+    let decreasing = Policy {
+        trend: Trend::Decreasing,
+        ..Policy::default()
+    };
+    assert_eq!(
+        check(&[1, 3, 6, 7, 9], &decreasing),
+        Err(Violation {
+            index: 1,
+            problem: Problem::Direction { increasing: false }
+        })
+    );
+    assert_eq!(check(&[9, 7, 6, 3, 1], &decreasing), Ok(()));
+
+    let wide = Policy {
+        gaps: 2..=5,
+        ..Policy::default()
+    };
+    assert!(check(&[1, 2, 7, 8, 9], &Policy::default()).is_err());
+    assert_eq!(check(&[1, 3, 8, 10], &wide), Ok(()));
+    assert_eq!(
+        check(&[1, 3, 4], &wide),
+        Err(Violation {
+            index: 2,
+            problem: Problem::Gap(1)
+        })
+    );
+}
+
+#[test]
+fn equal_levels_follow_the_gaps_whatever_the_trend() {
+    for trend in [Trend::Increasing, Trend::Decreasing, Trend::Either] {
+        let flat = Policy {
+            gaps: 0..=3,
+            trend,
+            removals: 0,
+        };
+        assert_eq!(check(&[2, 2, 2], &flat), Ok(()), "{:?}", trend);
+        assert_eq!(
+            check(
+                &[2, 2, 2],
+                &Policy {
+                    gaps: 1..=3,
+                    ..flat
+                }
+            ),
+            Err(Violation {
+                index: 1,
+                problem: Problem::Gap(0)
+            }),
+            "{:?}",
+            trend
+        );
+    }
+
+    let flat = Policy {
+        gaps: 0..=3,
+        ..Policy::default()
+    };
+    assert_eq!(check(&[1, 1, 2], &flat), Ok(()));
+    assert_eq!(check(&[3, 3, 2, 2, 1], &flat), Ok(()));
+    assert_eq!(
+        check(&[1, 1, 2, 1], &flat),
+        Err(Violation {
+            index: 3,
+            problem: Problem::Direction { increasing: true }
+        })
+    );
+    assert_eq!(removed(&[1, 1, 2, 1, 2], &flat), Some(vec![2]));
+}
+
+#[test]
+fn policies_take_the_parameters_over_the_puzzle_rules() {
+    let params = Params {
+        max_gap: Some(5),
+        removals: Some(0),
+        ..Params::default()
+    };
+    assert_eq!(
+        Policy::new(&params),
+        Ok(Policy {
+            gaps: 1..=5,
+            removals: 0,
+            ..Policy::default()
+        })
+    );
+    // Against the default maximum too
+    let params = Params {
+        min_gap: Some(4),
+        ..Params::default()
+    };
+    assert_eq!(
+        Policy::new(&params),
+        Err(InvertedGaps {
+            min_gap: 4,
+            max_gap: 3
+        })
+    );
+}
+
+#[test]
+fn min_gap_above_max_gap_is_rejected() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([
+            "run",
+            "2024",
+            "2",
+            "--example",
+            "--min-gap",
+            "3",
+            "--max-gap",
+            "2",
+        ])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(2), "{}", stderr);
+    assert!(stderr.contains("--min-gap"), "{}", stderr);
+}
+
+#[test]
+fn removes_several_levels() {
+    let twice = Policy {
+        removals: 2,
+        ..Policy::default()
+    };
+    assert_eq!(removed(&[1, 9, 2, 3, 9, 4], &twice), Some(vec![1, 4]));
+    assert_eq!(removed(&[1, 9, 2, 3, 9, 4], &Policy::default()), None);
+    // A long report with a stray level every so often stays fast
+    let mut levels: Vec<u32> = (0..100_000).collect();
+    levels[500] = 7;
+    levels[70_000] = 1;
+    assert_eq!(removed(&levels, &twice), Some(vec![500, 70_000]));
+}
+
+#[test]
+fn part_2_dampens_as_the_parameters_say() {
+    let solve = |params: &Params, input: &str| {
+        let solver = get_solver(Puzzle::new(2024, 2), params).unwrap();
+        let parsed = solver.parse(input).unwrap();
+        [Part::One, Part::Two].map(|part| solver.solve(&*parsed, part))
+    };
+    let input = "1 9 2 3 9 4\n7 6 4 2 1\n1 3 2 4 5";
+
+    assert_eq!(
+        solve(&Params::default(), input),
+        [Answer::Number(1), Answer::Number(2)]
+    );
+    let params = Params {
+        removals: Some(2),
+        trend: Some(Trend::Increasing),
+        ..Params::default()
+    };
+    assert_eq!(
+        solve(&params, input),
+        [Answer::Number(0), Answer::Number(2)]
+    );
+    let params = Params {
+        removals: Some(0),
+        min_gap: Some(2),
+        max_gap: Some(2),
+        ..Params::default()
+    };
+    assert_eq!(
+        solve(&params, input),
+        [Answer::Number(0), Answer::Number(0)]
+    );
+}
@@ -0,0 +1,65 @@
+//! Every puzzle run on the examples published with it, against their known answers.
+
+use std::path::Path;
+
+use aoc::input::{self, EXAMPLE_DIR};
+use aoc::{get_solver, Answer, Mode, Params, Part, Puzzle};
+
+mod y2024;
+
+fn read_example(puzzle: Puzzle, file: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(EXAMPLE_DIR)
+        .join(puzzle.year.to_string())
+        .join(file);
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e))
+}
+
+fn solve_text(puzzle: Puzzle, params: &Params, content: &str) -> (Answer, Answer) {
+    let solver = get_solver(puzzle, params).unwrap();
+    let input = solver
+        .parse(content)
+        .unwrap_or_else(|e| panic!("{}: {}", puzzle, e));
+
+    (
+        solver.solve(input.as_ref(), Part::One),
+        solver.solve(input.as_ref(), Part::Two),
+    )
+}
+
+fn solve_file(puzzle: Puzzle, params: &Params, file: &str) -> (Answer, Answer) {
+    solve_text(puzzle, params, &read_example(puzzle, file))
+}
+
+fn solve_with(puzzle: Puzzle, params: &Params) -> (Answer, Answer) {
+    solve_file(puzzle, params, &input::example_file_name(puzzle.day))
+}
+
+fn solve(puzzle: Puzzle) -> (Answer, Answer) {
+    solve_with(puzzle, &Params::with_mode(Mode::Example))
+}
+
+fn number(n: u64) -> Answer {
+    Answer::Number(n.into())
+}
+
+fn text(s: &str) -> Answer {
+    Answer::Text(s.to_string())
+}
+
+#[test]
+fn examples_saved_by_any_editor() {
+    let params = Params::with_mode(Mode::Example);
+    for puzzle in aoc::puzzles() {
+        let content = read_example(puzzle, &input::example_file_name(puzzle.day));
+        let saved = format!("\u{feff}{}\r\n\r\n", content.replace('\n', " \t\r\n"));
+
+        assert_eq!(
+            solve_text(puzzle, &params, &saved),
+            solve_text(puzzle, &params, &content),
+            "{}",
+            puzzle
+        );
+    }
+}
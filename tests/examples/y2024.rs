@@ -0,0 +1,264 @@
+//! The 2024 puzzles on their examples.
+
+use aoc::{get_solver, Answer, Mode, Params, Puzzle, Sort};
+
+use super::{number, solve_file, solve_text, solve_with, text};
+
+fn puzzle(day: u8) -> Puzzle {
+    Puzzle::new(2024, day)
+}
+
+fn solve(day: u8) -> (Answer, Answer) {
+    super::solve(puzzle(day))
+}
+
+#[test]
+fn day1() {
+    assert_eq!(solve(1), (number(11), number(31)));
+}
+
+#[test]
+fn day1_with_the_radix_sort() {
+    let params = Params {
+        sort: Some(Sort::Radix),
+        ..Params::with_mode(Mode::Example)
+    };
+    assert_eq!(solve_with(puzzle(1), &params), (number(11), number(31)));
+}
+
+#[test]
+fn day2() {
+    assert_eq!(solve(2), (number(2), number(4)));
+}
+
+#[test]
+fn day3() {
+    assert_eq!(solve(3), (number(161), number(48)));
+}
+
+#[test]
+fn day4() {
+    assert_eq!(solve(4), (number(18), number(9)));
+}
+
+#[test]
+fn day5() {
+    assert_eq!(solve(5), (number(143), number(123)));
+}
+
+#[test]
+fn day6() {
+    assert_eq!(solve(6), (number(41), number(6)));
+}
+
+#[test]
+fn day7() {
+    assert_eq!(solve(7), (number(3749), number(11387)));
+}
+
+#[test]
+fn day8() {
+    assert_eq!(solve(8), (number(14), number(34)));
+}
+
+#[test]
+fn day9() {
+    assert_eq!(solve(9), (number(1928), number(2858)));
+}
+
+#[test]
+fn day10() {
+    assert_eq!(solve(10), (number(36), number(81)));
+}
+
+#[test]
+fn day11() {
+    assert_eq!(solve(11), (number(55312), number(65601038650482)));
+}
+
+#[test]
+fn day12() {
+    assert_eq!(solve(12), (number(140), Answer::Unsolved));
+}
+
+#[test]
+fn day13() {
+    assert_eq!(solve(13), (number(480), number(875318608908)));
+}
+
+// The example has no Christmas tree, so only part 1 has a published answer
+#[test]
+fn day14() {
+    assert_eq!(solve(14).0, number(12));
+}
+
+#[test]
+fn day14_without_a_christmas_tree() {
+    let params = Params {
+        width: Some(2),
+        height: Some(2),
+        ..Params::with_mode(Mode::Example)
+    };
+    assert_eq!(
+        solve_text(puzzle(14), &params, "p=0,0 v=1,1\np=0,0 v=1,1").1,
+        Answer::Unsolved
+    );
+}
+
+#[test]
+fn day14_rejects_robots_outside_the_bathroom() {
+    let params = Params {
+        width: Some(11),
+        height: Some(7),
+        ..Params::with_mode(Mode::Real)
+    };
+    let solver = get_solver(puzzle(14), &params).unwrap();
+    let error = solver.parse("p=0,4 v=3,-3\np=11,3 v=-1,2").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.message, "expected a robot inside the 11x7 bathroom");
+
+    // Without a size, the puzzle one
+    let solver = get_solver(puzzle(14), &Params::with_mode(Mode::Real)).unwrap();
+    assert!(solver.parse("p=100,102 v=3,-3").is_ok());
+    assert!(solver.parse("p=101,0 v=3,-3").is_err());
+}
+
+#[test]
+fn day14_with_the_example_bathroom_size() {
+    let params = Params {
+        width: Some(11),
+        height: Some(7),
+        ..Params::with_mode(Mode::Example)
+    };
+    assert_eq!(solve_with(puzzle(14), &params).0, number(12));
+}
+
+#[test]
+fn day15() {
+    assert_eq!(solve(15), (number(10092), Answer::Unsolved));
+}
+
+#[test]
+fn day16() {
+    assert_eq!(solve(16), (number(7036), number(45)));
+}
+
+#[test]
+fn day16_second_example() {
+    let params = Params::with_mode(Mode::Example);
+    assert_eq!(
+        solve_file(puzzle(16), &params, "example-day16-2.txt"),
+        (number(11048), number(64))
+    );
+}
+
+// The example of part 2, a program that outputs itself
+#[test]
+fn day17() {
+    assert_eq!(solve(17).1, number(117440));
+}
+
+#[test]
+fn day17_first_example() {
+    let params = Params::with_mode(Mode::Example);
+    assert_eq!(
+        solve_file(puzzle(17), &params, "example-day17-2.txt").0,
+        text("4,6,3,5,6,3,5,2,1,0")
+    );
+}
+
+#[test]
+fn day18() {
+    assert_eq!(solve(18), (number(22), text("6,1")));
+}
+
+#[test]
+fn day18_with_the_example_memory_size() {
+    let params = Params {
+        width: Some(7),
+        height: Some(7),
+        bytes: Some(12),
+        ..Params::with_mode(Mode::Real)
+    };
+    assert_eq!(solve_with(puzzle(18), &params), (number(22), text("6,1")));
+}
+
+#[test]
+fn day18_without_a_path_or_a_blocking_byte() {
+    let params = Params {
+        bytes: Some(100_000),
+        ..Params::with_mode(Mode::Example)
+    };
+    assert_eq!(
+        solve_with(puzzle(18), &params),
+        (Answer::Unsolved, text("6,1"))
+    );
+
+    let params = Params::with_mode(Mode::Real);
+    // A byte on the exit, then bytes that never cut it off
+    assert_eq!(
+        solve_text(puzzle(18), &params, "1,2"),
+        (Answer::Unsolved, text("1,2"))
+    );
+    assert_eq!(
+        solve_text(puzzle(18), &params, "2,0\n0,2"),
+        (number(4), Answer::Unsolved)
+    );
+}
+
+#[test]
+fn day19() {
+    assert_eq!(solve(19), (number(6), number(16)));
+}
+
+// With the example threshold of 50 picoseconds instead of 100
+#[test]
+fn day20() {
+    assert_eq!(solve(20), (number(1), number(285)));
+}
+
+#[test]
+fn day21() {
+    assert_eq!(solve(21), (number(126384), Answer::Unsolved));
+}
+
+#[test]
+fn day22() {
+    assert_eq!(solve(22), (number(37327623), Answer::Unsolved));
+}
+
+#[test]
+fn day23() {
+    assert_eq!(solve(23), (number(7), text("co,de,ka,ta")));
+}
+
+#[test]
+fn day23_without_a_triangle() {
+    let params = Params::with_mode(Mode::Real);
+    assert_eq!(
+        solve_text(puzzle(23), &params, "kh-tc"),
+        (number(0), Answer::Unsolved)
+    );
+
+    let solver = get_solver(puzzle(23), &params).unwrap();
+    for (input, column, message) in [
+        ("", 1, "expected connections like `kh-tc`"),
+        ("kh-", 4, "expected the name of a computer"),
+        ("-tc", 1, "expected the name of a computer"),
+        ("kh-tc-qp", 6, "expected a single `-` between two computers"),
+    ] {
+        let error = solver.parse(input).err().unwrap();
+        assert_eq!(error.column, column, "{}", input);
+        assert_eq!(error.message, message, "{}", input);
+    }
+}
+
+#[test]
+fn day24() {
+    assert_eq!(solve(24), (number(2024), Answer::Unsolved));
+}
+
+#[test]
+fn day25() {
+    assert_eq!(solve(25), (number(3), Answer::Unsolved));
+}
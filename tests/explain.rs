@@ -0,0 +1,91 @@
+use aoc::explain::{explain, get_explainer, Explanation};
+use aoc::y2024::day2::{self, Diagnosis, Policy, Problem, Violation};
+use aoc::{Answer, Mode, Params, Part, Puzzle};
+
+fn violation(index: usize, problem: Problem) -> Violation {
+    Violation { index, problem }
+}
+
+fn check(levels: &[u32]) -> Result<(), Violation> {
+    day2::check(levels, &Policy::default())
+}
+
+fn diagnose(levels: &[u32]) -> Diagnosis {
+    day2::diagnose(levels, &Policy::default())
+}
+
+#[test]
+fn finds_the_first_level_breaking_the_rules() {
+    assert_eq!(check(&[7, 6, 4, 2, 1]), Ok(()));
+    assert_eq!(check(&[1, 2, 7, 8, 9]), Err(violation(2, Problem::Gap(5))));
+    assert_eq!(check(&[8, 6, 4, 4, 1]), Err(violation(3, Problem::Gap(0))));
+    assert_eq!(
+        check(&[1, 3, 2, 4, 5]),
+        Err(violation(2, Problem::Direction { increasing: true }))
+    );
+    assert_eq!(
+        check(&[9, 8, 9, 1]),
+        Err(violation(2, Problem::Direction { increasing: false }))
+    );
+    assert_eq!(check(&[]), Ok(()));
+    assert_eq!(check(&[4]), Ok(()));
+}
+
+#[test]
+fn tells_which_level_the_dampener_removes() {
+    assert_eq!(diagnose(&[1, 3, 6, 7, 9]), Diagnosis::Safe);
+    assert_eq!(
+        diagnose(&[1, 3, 2, 4, 5]),
+        Diagnosis::Dampened {
+            violation: violation(2, Problem::Direction { increasing: true }),
+            removed: vec![1]
+        }
+    );
+    // The violation shows up after the level to remove
+    assert_eq!(
+        diagnose(&[3, 2, 3, 4, 5]),
+        Diagnosis::Dampened {
+            violation: violation(2, Problem::Direction { increasing: false }),
+            removed: vec![0]
+        }
+    );
+    assert_eq!(
+        diagnose(&[9, 7, 6, 2, 1]),
+        Diagnosis::Unsafe(violation(3, Problem::Gap(4)))
+    );
+}
+
+#[test]
+fn explains_every_report_of_the_example() {
+    let params = Params::with_mode(Mode::Example);
+    let Explanation { lines, answers } = explain(Puzzle::new(2024, 2), None, &params, None)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(lines.len(), 6);
+    assert_eq!(lines[0], "line 1: 7 6 4 2 1: safe");
+    assert_eq!(
+        lines[3],
+        "line 4: 1 3 2 4 5: unsafe, index 2 goes down while the report increases; safe once \
+         the dampener removes index 1"
+    );
+    assert_eq!(
+        answers,
+        [
+            (Part::One, Answer::Number(2)),
+            (Part::Two, Answer::Number(4))
+        ]
+    );
+}
+
+#[test]
+fn days_without_explanation_are_not_explained() {
+    let params = Params::with_mode(Mode::Example);
+    assert!(get_explainer(Puzzle::new(2024, 4), &params).is_none());
+    // Same day as an explained one, another year
+    assert!(get_explainer(Puzzle::new(2023, 2), &params).is_none());
+    assert_eq!(
+        explain(Puzzle::new(2024, 4), None, &params, None).unwrap(),
+        None
+    );
+}
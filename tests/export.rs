@@ -0,0 +1,85 @@
+use std::path::Path;
+
+use aoc::export::{self, ImageFormat, TILE_PIXELS};
+use aoc::geometry::Vec2;
+use aoc::grid::Grid;
+use aoc::visualize::{Color, Frame, Tile};
+use aoc::{Mode, Params, Puzzle};
+
+/// Two tiles side by side, a red one and one without color.
+fn frame() -> Frame {
+    let mut grid = Grid::new(2, 1, Tile::plain('.'));
+    grid[Vec2::new(0, 0)] = Tile::colored('#', Color::Rgb(255, 0, 0));
+    Frame::new("a <small> frame", grid)
+}
+
+#[test]
+fn formats_follow_the_extension() {
+    assert_eq!(
+        ImageFormat::from_path(Path::new("tree.PNG")),
+        Some(ImageFormat::Png)
+    );
+    assert_eq!(
+        ImageFormat::from_path(Path::new("out/tree.svg")),
+        Some(ImageFormat::Svg)
+    );
+    assert_eq!(ImageFormat::from_path(Path::new("tree.gif")), None);
+    assert_eq!(ImageFormat::from_path(Path::new("tree")), None);
+}
+
+#[test]
+fn ppm_scales_every_tile() {
+    let image = export::ppm(&frame());
+    let header = format!("P6\n{} {}\n255\n", 2 * TILE_PIXELS, TILE_PIXELS);
+
+    assert!(image.starts_with(header.as_bytes()));
+    let pixels = &image[header.len()..];
+    assert_eq!(pixels.len(), 2 * TILE_PIXELS * TILE_PIXELS * 3);
+    assert_eq!(pixels[..3], [255, 0, 0]);
+    assert_eq!(pixels[(TILE_PIXELS - 1) * 3..TILE_PIXELS * 3], [255, 0, 0]);
+    assert_ne!(pixels[TILE_PIXELS * 3..TILE_PIXELS * 3 + 3], [255, 0, 0]);
+}
+
+#[test]
+fn png_chunks_are_well_formed() {
+    let image = export::png(&frame());
+
+    assert!(image.starts_with(b"\x89PNG\r\n\x1a\n"));
+    assert_eq!(&image[12..16], b"IHDR");
+    assert_eq!(
+        image[16..24],
+        [0, 0, 0, 2 * TILE_PIXELS as u8, 0, 0, 0, TILE_PIXELS as u8]
+    );
+    // The IEND chunk, whose checksum never changes
+    assert!(image.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60, 0x82]));
+
+    // Filter byte then RGB pixels for each row, stored in a single deflate block
+    let raw = TILE_PIXELS * (1 + 2 * TILE_PIXELS * 3);
+    let idat_length = u32::from_be_bytes(image[33..37].try_into().unwrap()) as usize;
+    assert_eq!(&image[37..41], b"IDAT");
+    assert_eq!(idat_length, 2 + 5 + raw + 4);
+}
+
+#[test]
+fn svg_draws_colored_tiles_over_a_background() {
+    let image = export::svg(&frame());
+
+    assert!(image.contains("<title>a &lt;small&gt; frame</title>"));
+    assert!(image.contains(r##"<rect x="0" y="0" width="1" height="1" fill="#ff0000"/>"##));
+    assert_eq!(image.matches("<rect").count(), 2);
+    assert!(image.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+fn exports_the_christmas_tree() {
+    let path = std::env::temp_dir().join(format!("aoc-export-{}.svg", std::process::id()));
+    let params = Params::with_mode(Mode::Example);
+
+    assert!(export::export(Puzzle::new(2024, 14), &params, None, &path).unwrap());
+    let image = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(image.contains("every robot on its own tile"));
+
+    assert!(!export::export(Puzzle::new(2024, 1), &params, None, &path).unwrap());
+    assert!(!path.exists());
+}
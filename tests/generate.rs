@@ -0,0 +1,92 @@
+use aoc::generate::generate;
+use aoc::{get_solver, Answer, Params, Part, Puzzle, DAYS};
+
+fn puzzle(day: u8) -> Puzzle {
+    Puzzle::new(2024, day)
+}
+
+fn solve(day: u8, input: &str, params: &Params) -> [Answer; 2] {
+    let solver = get_solver(puzzle(day), params).unwrap();
+    let parsed = solver
+        .parse(input)
+        .unwrap_or_else(|e| panic!("generated input does not parse: {}\n{}", e, input));
+    [Part::One, Part::Two].map(|part| solver.solve(&*parsed, part))
+}
+
+#[test]
+fn seeds_reproduce_inputs() {
+    let params = Params::default();
+    for day in DAYS {
+        let input = generate(puzzle(day), 10, 7, &params).unwrap();
+        assert_eq!(
+            generate(puzzle(day), 10, 7, &params).unwrap(),
+            input,
+            "day {}",
+            day
+        );
+        assert_ne!(
+            generate(puzzle(day), 10, 8, &params).unwrap(),
+            input,
+            "day {}",
+            day
+        );
+    }
+    assert_eq!(generate(puzzle(26), 10, 7, &params), None);
+    assert_eq!(generate(Puzzle::new(2023, 1), 10, 7, &params), None);
+}
+
+#[test]
+fn every_day_solves_generated_inputs() {
+    let params = Params::default();
+    for day in DAYS {
+        for seed in 0..3 {
+            // Past 20, register A of day 17 would have more octal digits than an i64 holds
+            for size in [1, 12, 21] {
+                let input = generate(puzzle(day), size, seed, &params).unwrap();
+                solve(day, &input, &params);
+            }
+        }
+    }
+}
+
+#[test]
+fn every_day_solves_inputs_of_the_default_size() {
+    let params = Params::default();
+    // The path searches of days 16 and 18 over a 100 by 100 grid take minutes unoptimized
+    for day in DAYS.filter(|day| ![16, 18].contains(day)) {
+        let input = generate(puzzle(day), 100, 0, &params).unwrap();
+        solve(day, &input, &params);
+    }
+}
+
+#[test]
+fn adder_without_swaps_adds() {
+    let params = Params {
+        swaps: Some(0),
+        ..Params::default()
+    };
+    let input = generate(puzzle(24), 40, 3, &params).unwrap();
+    let number = |prefix: char| -> u64 {
+        input
+            .lines()
+            .filter_map(|line| line.strip_prefix(prefix)?.split_once(": "))
+            .map(|(bit, value)| value.parse::<u64>().unwrap() << bit.parse::<u64>().unwrap())
+            .sum()
+    };
+
+    assert_eq!(
+        solve(24, &input, &params)[0],
+        Answer::from(number('x') + number('y'))
+    );
+}
+
+#[test]
+fn lan_party_has_a_single_largest_clique() {
+    let params = Params::default();
+    let input = generate(puzzle(23), 100, 5, &params).unwrap();
+
+    match &solve(23, &input, &params)[1] {
+        Answer::Text(password) => assert_eq!(password.split(',').count(), 13),
+        answer => panic!("unexpected answer {:?}", answer),
+    }
+}
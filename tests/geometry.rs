@@ -0,0 +1,65 @@
+use aoc::geometry::{Direction4, Direction8, Vec2};
+
+#[test]
+fn vectors_add_subtract_and_scale() {
+    let a = Vec2::new(1, 2);
+    let b = Vec2::new(-3, 4);
+
+    assert_eq!(a + b, Vec2::new(-2, 6));
+    assert_eq!(a - b, Vec2::new(4, -2));
+    assert_eq!(b * 3, Vec2::new(-9, 12));
+    assert_eq!(-a, Vec2::new(-1, -2));
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    assert_eq!(c, b);
+}
+
+#[test]
+fn distances() {
+    let a = Vec2::new(1, -2);
+    let b = Vec2::new(4, 5);
+
+    assert_eq!(a.manhattan(b), 10);
+    assert_eq!(a.chebyshev(b), 7);
+    assert_eq!(b.manhattan(a), a.manhattan(b));
+    assert_eq!(a.manhattan(a), 0);
+}
+
+#[test]
+fn orthogonal_turns() {
+    assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+    assert_eq!(Direction4::Left.turn_right(), Direction4::Up);
+    assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+    assert_eq!(Direction4::Down.reverse(), Direction4::Up);
+
+    for direction in Direction4::ALL {
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.reverse().offset(), -direction.offset());
+        assert_eq!(direction.offset().manhattan(Vec2::ZERO), 1);
+    }
+}
+
+#[test]
+fn compass_turns() {
+    assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+    assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+    assert_eq!(Direction8::DownLeft.reverse(), Direction8::UpRight);
+    assert_eq!(Direction8::from(Direction4::Left), Direction8::Left);
+
+    for direction in Direction8::ALL {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.reverse().offset(), -direction.offset());
+        assert_eq!(direction.offset().chebyshev(Vec2::ZERO), 1);
+    }
+}
+
+#[test]
+fn arrows_round_trip() {
+    for direction in Direction4::ALL {
+        assert_eq!(Direction4::from_char(direction.to_char()), Some(direction));
+    }
+    assert_eq!(Direction4::from_char('v'), Some(Direction4::Down));
+    assert_eq!(Direction4::from_char('x'), None);
+}
@@ -0,0 +1,78 @@
+use aoc::geometry::Vec2;
+use aoc::grid::Grid;
+use aoc::parse::Source;
+
+fn parse(text: &str) -> Grid<char> {
+    Grid::parse(
+        &Source::new(1, text),
+        text,
+        |c| matches!(c, '.' | '#').then_some(c),
+        "`.` or `#`",
+    )
+    .unwrap()
+}
+
+#[test]
+fn parses_lines_of_cells() {
+    let grid = parse("..#\n#..\n");
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Vec2::new(0, 2)], '#');
+    assert_eq!(grid[Vec2::new(1, 0)], '#');
+    assert_eq!(grid.find(|c| *c == '#'), Some(Vec2::new(0, 2)));
+}
+
+#[test]
+fn reports_unexpected_cells_and_ragged_lines() {
+    let text = "..#\n#x.\n";
+    let error = Grid::parse(
+        &Source::new(6, text),
+        text,
+        |c| matches!(c, '.' | '#').then_some(c),
+        "`.` or `#`",
+    )
+    .unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+    assert_eq!(error.text, "x");
+
+    let text = "..#\n#.\n";
+    let error = Grid::parse(&Source::new(6, text), text, Some, "a cell").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+
+    assert!(Grid::parse(&Source::new(6, ""), "", Some, "a cell").is_err());
+}
+
+#[test]
+fn signed_indexing_is_bounds_checked() {
+    let grid = parse("..\n..\n");
+
+    assert!(grid.contains(Vec2::new(1, 1)));
+    assert_eq!(grid.get(Vec2::new(-1, 0)), None);
+    assert_eq!(grid.get(Vec2::new(0, 2)), None);
+    assert_eq!(grid.get(Vec2::new(2, 0)), None);
+}
+
+#[test]
+fn neighbors_stay_inside_the_grid() {
+    let grid = Grid::new(3, 3, 0);
+
+    assert_eq!(
+        grid.neighbors4(Vec2::new(0, 0)).collect::<Vec<_>>(),
+        [Vec2::new(0, 1), Vec2::new(1, 0)]
+    );
+    assert_eq!(grid.neighbors4(Vec2::new(1, 1)).count(), 4);
+    assert_eq!(grid.neighbors8(Vec2::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbors8(Vec2::new(1, 1)).count(), 8);
+}
+
+#[test]
+fn renders_one_line_per_line() {
+    let mut grid = parse("..#\n#..\n");
+    grid[Vec2::new(1, 1)] = '#';
+
+    assert_eq!(grid.to_string(), "..#\n##.\n");
+    assert_eq!(
+        Grid::from_fn(3, 2, |c| c.line * 3 + c.column).to_string(),
+        "012\n345\n"
+    );
+}
@@ -0,0 +1,77 @@
+use aoc::generate::generate;
+use aoc::y2024::day3::{tokenize, Instruction, Machine, Token};
+use aoc::{Params, Puzzle};
+use regex::Regex;
+
+fn instructions(memory: &str) -> Vec<Instruction> {
+    tokenize(memory).map(|token| token.instruction).collect()
+}
+
+#[test]
+fn finds_instructions_with_their_offsets() {
+    let tokens: Vec<Token> = tokenize("xmul(2,4)&don't()_do()").collect();
+
+    assert_eq!(
+        tokens,
+        [
+            Token {
+                offset: 1,
+                instruction: Instruction::Mul(2, 4)
+            },
+            Token {
+                offset: 10,
+                instruction: Instruction::Dont
+            },
+            Token {
+                offset: 18,
+                instruction: Instruction::Do
+            },
+        ]
+    );
+}
+
+#[test]
+fn skips_corrupted_instructions() {
+    assert_eq!(
+        instructions("mul(4*mul(6,9!?(12,34)mul ( 2 , 4 )mul(1234,5)mul(123,4)"),
+        [Instruction::Mul(123, 4)]
+    );
+    assert_eq!(
+        instructions("mul(mul(1,2)don't(do()dodo()é"),
+        [Instruction::Mul(1, 2), Instruction::Do, Instruction::Do]
+    );
+    assert_eq!(instructions("mul(,2)mul(2,)mul(3,4"), []);
+}
+
+#[test]
+fn runs_with_or_without_conditionals() {
+    let tokens: Vec<Token> = tokenize("mul(2,3)don't()mul(4,5)do()mul(6,7)").collect();
+
+    assert_eq!(Machine::new(false).run(&tokens).sum, 6 + 20 + 42);
+    let machine = Machine::new(true).run(&tokens);
+    assert_eq!(machine.sum, 6 + 42);
+    assert!(machine.enabled);
+
+    let mut machine = Machine::new(true);
+    machine.execute(Instruction::Dont);
+    machine.execute(Instruction::Mul(999, 999));
+    assert_eq!((machine.sum, machine.enabled), (0, false));
+}
+
+#[test]
+fn agrees_with_a_regex_on_generated_memory() {
+    let muls = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+    for seed in 0..10 {
+        let memory = generate(Puzzle::new(2024, 3), 200, seed, &Params::default()).unwrap();
+        let expected: Vec<Instruction> = muls
+            .captures_iter(&memory)
+            .map(|caps| Instruction::Mul(caps[1].parse().unwrap(), caps[2].parse().unwrap()))
+            .collect();
+        let found: Vec<Instruction> = instructions(&memory)
+            .into_iter()
+            .filter(|instruction| matches!(instruction, Instruction::Mul(..)))
+            .collect();
+
+        assert_eq!(found, expected, "seed {}", seed);
+    }
+}
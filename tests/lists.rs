@@ -0,0 +1,190 @@
+use std::fs;
+
+use aoc::generate::generate;
+use aoc::runner::{run_day, RunError};
+use aoc::{get_solver, Answer, Metric, Pairs, Params, ParseError, Part, Puzzle, Sort};
+
+const DAY1: Puzzle = Puzzle { year: 2024, day: 1 };
+
+fn params(sort: Sort) -> Params {
+    Params {
+        sort: Some(sort),
+        ..Params::default()
+    }
+}
+
+fn solve(sort: Sort, input: &str) -> Result<[Answer; 2], ParseError> {
+    solve_with(&params(sort), input)
+}
+
+fn solve_with(params: &Params, input: &str) -> Result<[Answer; 2], ParseError> {
+    let solver = get_solver(DAY1, params).unwrap();
+    let parsed = solver.parse(input)?;
+    Ok([Part::One, Part::Two].map(|part| solver.solve(&*parsed, part)))
+}
+
+/// Runs day 1 on `content` saved to a file of its own, as read by the runner.
+fn run(name: &str, sort: Sort, content: &str) -> Result<[Answer; 2], RunError> {
+    let path = std::env::temp_dir().join(format!("aoc-lists-{}-{}", name, std::process::id()));
+    fs::write(&path, content).unwrap();
+    let result = run_day(DAY1, None, &params(sort), Some(&path));
+    fs::remove_file(path).unwrap();
+    result.map(|result| [0, 1].map(|i| result.parts[i].answer.clone()))
+}
+
+#[test]
+fn radix_sort_agrees_with_heaps() {
+    for seed in 0..10 {
+        let input = generate(DAY1, 1000, seed, &Params::default()).unwrap();
+        assert_eq!(
+            solve(Sort::Radix, &input).unwrap(),
+            solve(Sort::Heap, &input).unwrap(),
+            "seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn sums_do_not_overflow() {
+    let far_apart = "4294967295   0\n4294967295   0";
+    let alike = "4294967295   4294967295\n".repeat(3);
+
+    for sort in [Sort::Heap, Sort::Radix] {
+        assert_eq!(
+            solve(sort, far_apart).unwrap()[0],
+            Answer::Number(2 * 4294967295)
+        );
+        assert_eq!(
+            solve(sort, &alike).unwrap()[1],
+            Answer::Number(9 * 4294967295)
+        );
+    }
+}
+
+#[test]
+fn answers_past_u64_stay_numbers() {
+    let params = Params {
+        metric: Some(Metric::Squared),
+        ..Params::default()
+    };
+    let squared = 4294967295u128 * 4294967295;
+    assert!(2 * squared > u128::from(u64::MAX));
+
+    let [distance, _] = solve_with(&params, "4294967295   0\n4294967295   0").unwrap();
+    assert_eq!(distance, Answer::Number(2 * squared));
+    assert_eq!(distance.kind(), "number");
+    assert_eq!(
+        serde_json::to_string(&distance).unwrap(),
+        (2 * squared).to_string()
+    );
+}
+
+// Sorted, the columns are 1 3 3 / 1 2 5 / 3 3 3
+const COLUMNS: &str = "3   5   3\n1   1   3\n3   2   3";
+
+fn text(s: &str) -> Answer {
+    Answer::Text(s.to_string())
+}
+
+#[test]
+fn compares_every_pair_of_columns() {
+    for sort in [Sort::Heap, Sort::Radix] {
+        let params = Params {
+            sort: Some(sort),
+            ..Params::default()
+        };
+        assert_eq!(
+            solve_with(&params, COLUMNS).unwrap(),
+            [text("3,2,5"), text("1,18,0")]
+        );
+
+        let params = Params {
+            pairs: Some(Pairs::First),
+            ..params
+        };
+        assert_eq!(
+            solve_with(&params, COLUMNS).unwrap(),
+            [text("3,2"), text("1,18")]
+        );
+    }
+}
+
+#[test]
+fn measures_distances_with_every_metric() {
+    for (metric, distances) in [
+        (Metric::Absolute, "3,2,5"),
+        (Metric::Squared, "5,4,9"),
+        (Metric::Max, "2,2,2"),
+    ] {
+        for sort in [Sort::Heap, Sort::Radix] {
+            let params = Params {
+                sort: Some(sort),
+                metric: Some(metric),
+                ..Params::default()
+            };
+            assert_eq!(
+                solve_with(&params, COLUMNS).unwrap()[0],
+                text(distances),
+                "{:?}",
+                metric
+            );
+        }
+    }
+
+    let params = Params {
+        metric: Some(Metric::Squared),
+        ..Params::default()
+    };
+    assert_eq!(
+        solve_with(&params, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3").unwrap(),
+        [Answer::Number(35), Answer::Number(31)]
+    );
+}
+
+#[test]
+fn every_line_has_as_many_columns_as_the_first() {
+    for (content, column, message) in [
+        ("1 2 3\n4 5", 4, "expected location ID 3 of 3"),
+        ("1 2\n4 5 6", 5, "expected 2 location IDs"),
+        ("1\n2", 2, "expected a second location ID"),
+    ] {
+        for sort in [Sort::Heap, Sort::Radix] {
+            let error = solve(sort, content).unwrap_err();
+            assert_eq!(error.column, column, "{}", content);
+            assert_eq!(error.message, message, "{}", content);
+        }
+    }
+}
+
+#[test]
+fn reads_the_input_line_by_line() {
+    let saved = "\u{feff}3   4\r\n4   3 \r\n2   5\n1   3\n3   9\n3   3\r\n\r\n\n";
+
+    for sort in [Sort::Heap, Sort::Radix] {
+        assert_eq!(
+            run("saved", sort, saved).unwrap(),
+            [Answer::Number(11), Answer::Number(31)]
+        );
+    }
+}
+
+#[test]
+fn read_errors_point_at_the_line() {
+    for (name, content, line, column) in [
+        ("number", "3   4\n4   x3\n", 2, 5),
+        ("missing", "3   4\n4\n", 2, 2),
+        ("blank", "3   4\n\n\n4   3\n", 2, 1),
+    ] {
+        let Err(RunError::Parse(streamed)) = run(name, Sort::Radix, content) else {
+            panic!("{} parsed", name);
+        };
+        assert_eq!((streamed.line, streamed.column), (line, column), "{}", name);
+        assert_eq!(
+            solve(Sort::Heap, content).unwrap_err(),
+            streamed,
+            "{}",
+            name
+        );
+    }
+}
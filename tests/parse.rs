@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+
+use aoc::parse::{lines, normalize, sections, Source};
+
+#[test]
+fn normalizes_what_editors_leave_behind() {
+    assert_eq!(normalize("\u{feff}1 2\r\n3 4  \r\n\r\n"), "1 2\n3 4");
+    assert_eq!(normalize("a\t\n\nb\n"), "a\n\nb");
+    assert_eq!(normalize("  indented\n"), "  indented");
+    assert!(matches!(normalize("a\n\nb"), Cow::Borrowed("a\n\nb")));
+}
+
+#[test]
+fn splits_lines_without_trailing_whitespace() {
+    assert_eq!(lines("a \r\nb\n\n").collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(lines("a\n\nb").collect::<Vec<_>>(), ["a", "", "b"]);
+    assert_eq!(lines("").count(), 0);
+}
+
+#[test]
+fn splits_sections_on_blank_lines() {
+    let text = "1|2\r\n3|4\r\n \r\n\r\n1,2\n";
+
+    assert_eq!(sections(text).collect::<Vec<_>>(), ["1|2\r\n3|4", "1,2"]);
+    assert_eq!(sections("\n\na\n").collect::<Vec<_>>(), ["a"]);
+    assert_eq!(sections("").count(), 0);
+}
+
+#[test]
+fn sections_keep_their_position_in_the_input() {
+    let text = "rules\n\nupdates\nx";
+    let source = Source::new(5, text);
+    let updates = sections(text).nth(1).unwrap();
+    let last = lines(updates).last().unwrap();
+
+    let error = source.error(last, "expected a number");
+    assert_eq!((error.line, error.column), (4, 1));
+}
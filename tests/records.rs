@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use aoc::runner::{DayResult, PartResult};
+use aoc::{Answer, Part, Puzzle};
+use serde_json::json;
+
+#[test]
+fn records_leave_out_unsolved_parts() {
+    let result = DayResult {
+        puzzle: Puzzle::new(2024, 12),
+        parse_duration: Duration::from_micros(3),
+        parts: vec![
+            PartResult {
+                part: Part::One,
+                answer: Answer::Number(140),
+                duration: Duration::from_nanos(1500),
+            },
+            PartResult {
+                part: Part::Two,
+                answer: Answer::Unsolved,
+                duration: Duration::from_nanos(10),
+            },
+        ],
+    };
+
+    assert_eq!(
+        serde_json::to_value(result.records()).unwrap(),
+        json!([{
+            "year": 2024,
+            "day": 12,
+            "part": 1,
+            "answer": 140,
+            "answer_type": "number",
+            "duration_ns": 1500,
+        }])
+    );
+}
+
+#[test]
+fn text_answers_are_strings() {
+    let result = DayResult {
+        puzzle: Puzzle::new(2024, 23),
+        parse_duration: Duration::ZERO,
+        parts: vec![PartResult {
+            part: Part::Two,
+            answer: Answer::Text("co,de,ka,ta".to_string()),
+            duration: Duration::from_nanos(42),
+        }],
+    };
+
+    assert_eq!(
+        serde_json::to_value(result.records()).unwrap(),
+        json!([{
+            "year": 2024,
+            "day": 23,
+            "part": 2,
+            "answer": "co,de,ka,ta",
+            "answer_type": "text",
+            "duration_ns": 42,
+        }])
+    );
+}
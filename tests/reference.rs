@@ -0,0 +1,88 @@
+use aoc::generate::generate;
+use aoc::reference::{check, cross_check, Check};
+use aoc::{Answer, Mode, Params, Part, Puzzle};
+
+fn puzzle(day: u8) -> Puzzle {
+    Puzzle::new(2024, day)
+}
+
+fn assert_agree(day: u8, checks: Vec<Check>, what: &str) {
+    for c in checks {
+        assert_ne!(c.agrees(), Some(false), "day {} on {}: {:?}", day, what, c);
+    }
+}
+
+#[test]
+fn references_agree_on_the_examples() {
+    let params = Params::with_mode(Mode::Example);
+    for day in [11, 13, 17, 20] {
+        let checks = cross_check(puzzle(day), None, &params, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(checks.len(), 2);
+        assert!(
+            checks.iter().all(|c| c.agrees() == Some(true)),
+            "{:?}",
+            checks
+        );
+    }
+}
+
+#[test]
+fn references_agree_on_generated_inputs() {
+    let params = Params::default();
+    for (day, part) in [(11, None), (13, None), (17, Some(Part::One)), (20, None)] {
+        for seed in 0..10 {
+            let input = generate(puzzle(day), 8, seed, &params).unwrap();
+            let checks = check(puzzle(day), part, &params, &input).unwrap().unwrap();
+            assert_agree(day, checks, &format!("seed {}", seed));
+        }
+    }
+}
+
+// The references take seconds on day 17 part 2, hence fewer seeds
+#[test]
+fn generated_inputs_are_within_reach_of_the_references() {
+    let params = Params::default();
+    for (day, part, seeds) in [
+        (13, Part::Two, 0..10),
+        (17, Part::Two, 0..2),
+        (20, Part::One, 0..10),
+        (20, Part::Two, 0..10),
+    ] {
+        for seed in seeds {
+            let input = generate(puzzle(day), 8, seed, &params).unwrap();
+            let checks = check(puzzle(day), Some(part), &params, &input)
+                .unwrap()
+                .unwrap();
+            let what = format!("day {} on seed {}: {:?}", day, seed, checks);
+            assert_eq!(checks[0].agrees(), Some(true), "{}", what);
+            assert_ne!(checks[0].answer, Answer::Number(0), "{}", what);
+        }
+    }
+}
+
+#[test]
+fn claw_machines_need_whole_and_non_negative_pushes() {
+    // Part 2 solutions with a fractional push of A and a negative push of B
+    let input = "Button A: X+73, Y+73\nButton B: X+41, Y+80\nPrize: X=9900, Y=13137\n\n\
+                 Button A: X+16, Y+32\nButton B: X+18, Y+56\nPrize: X=1582, Y=3304";
+    let checks = check(puzzle(13), Some(Part::Two), &Params::default(), input)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(checks[0].answer, Answer::Number(0));
+    assert_agree(13, checks, "unwinnable prizes");
+}
+
+#[test]
+fn days_without_reference_are_not_checked() {
+    assert_eq!(
+        check(puzzle(1), None, &Params::default(), "3   4").unwrap(),
+        None
+    );
+    assert_eq!(
+        check(Puzzle::new(2023, 11), None, &Params::default(), "125 17").unwrap(),
+        None
+    );
+}
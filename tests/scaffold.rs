@@ -0,0 +1,171 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc::scaffold::{self, ScaffoldError};
+use aoc::Puzzle;
+
+const LIB: &str = "\
+pub mod grid;
+pub mod y2024;
+
+pub const YEARS: &[u16] = &[2024];
+
+pub fn get_solver(puzzle: Puzzle, params: &Params) -> Option<Box<dyn Solver>> {
+    match puzzle.year {
+        2024 => y2024::get_solver(puzzle.day, params),
+        _ => None,
+    }
+}
+";
+
+const MODULE: &str = "\
+use crate::{Params, Solver};
+
+pub mod day1;
+pub mod day10;
+pub mod day3;
+
+pub fn get_solver(day: u8, params: &Params) -> Option<Box<dyn Solver>> {
+    let solver: Box<dyn Solver> = match day {
+        1 => Box::new(day1::Day1),
+        3 => Box::new(day3::Day3),
+        10 => Box::new(day10::Day10::new(params)),
+        _ => return None,
+    };
+    Some(solver)
+}
+";
+
+const TESTS_MAIN: &str = "\
+fn solve(puzzle: Puzzle) -> (Answer, Answer) {
+    todo!()
+}
+
+mod y2024;
+";
+
+const TESTS: &str = "\
+#[test]
+fn day1() {
+    assert_eq!(solve(1), (number(1), number(2)));
+}
+
+#[test]
+#[ignore = \"slow\"]
+fn day3() {
+    assert_eq!(solve(3), (number(3), number(4)));
+}
+";
+
+/// A crate with days 1, 3 and 10 of 2024, in a directory of its own.
+fn crate_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src/examples/2024")).unwrap();
+    fs::create_dir_all(root.join("src/y2024")).unwrap();
+    fs::create_dir_all(root.join("tests/examples")).unwrap();
+    fs::write(root.join("src/lib.rs"), LIB).unwrap();
+    fs::write(root.join("src/y2024/mod.rs"), MODULE).unwrap();
+    fs::write(root.join("tests/examples/main.rs"), TESTS_MAIN).unwrap();
+    fs::write(root.join("tests/examples/y2024.rs"), TESTS).unwrap();
+    root
+}
+
+fn read(root: &Path, file: &str) -> String {
+    fs::read_to_string(root.join(file)).unwrap()
+}
+
+#[test]
+fn registers_a_new_day_in_order() {
+    let root = crate_root("order");
+    let paths = scaffold::new_day(&root, Puzzle::new(2024, 2)).unwrap();
+
+    assert_eq!(paths.len(), 4);
+    assert!(read(&root, "src/y2024/day2.rs").contains("impl Solution for Day2 {"));
+    assert_eq!(read(&root, "src/examples/2024/example-day2.txt"), "");
+    assert_eq!(read(&root, "src/lib.rs"), LIB);
+
+    let module = read(&root, "src/y2024/mod.rs");
+    assert!(module.contains("pub mod day10;\npub mod day2;\npub mod day3;\n"));
+    assert!(module.contains(
+        "        1 => Box::new(day1::Day1),\n        2 => Box::new(day2::Day2),\n        3 =>"
+    ));
+
+    let tests = read(&root, "tests/examples/y2024.rs");
+    let (day1, day2, day3) = (
+        tests.find("fn day1()").unwrap(),
+        tests.find("fn day2()").unwrap(),
+        tests.find("fn day3()").unwrap(),
+    );
+    assert!(day1 < day2 && day2 < day3);
+    assert!(tests.contains("}\n\n#[test]\n#[ignore = \"waiting for the example"));
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn days_past_the_last_one_go_at_the_end() {
+    let root = crate_root("last");
+    scaffold::new_day(&root, Puzzle::new(2024, 25)).unwrap();
+
+    let module = read(&root, "src/y2024/mod.rs");
+    assert!(module.contains("pub mod day10;\npub mod day25;\npub mod day3;\n"));
+    assert!(module
+        .contains("Day10::new(params)),\n        25 => Box::new(day25::Day25),\n        _ =>"));
+    assert!(read(&root, "tests/examples/y2024.rs").ends_with(
+        "fn day25() {\n    assert_eq!(solve(25), (Answer::Unsolved, Answer::Unsolved));\n}\n"
+    ));
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn first_day_of_a_year_starts_the_year() {
+    let root = crate_root("year");
+    let paths = scaffold::new_day(&root, Puzzle::new(2023, 5)).unwrap();
+
+    assert_eq!(paths.len(), 6);
+    assert!(read(&root, "src/y2023/day5.rs").contains("impl Solution for Day5 {"));
+    assert_eq!(read(&root, "src/examples/2023/example-day5.txt"), "");
+    let module = read(&root, "src/y2023/mod.rs");
+    assert!(module.contains("pub mod day5;\n"));
+    assert!(module.contains("        5 => Box::new(day5::Day5),\n        _ =>"));
+
+    let lib = read(&root, "src/lib.rs");
+    assert!(lib.contains("pub mod grid;\npub mod y2023;\npub mod y2024;\n"));
+    assert!(lib.contains("pub const YEARS: &[u16] = &[2023, 2024];"));
+    assert!(lib.contains("        2023 => y2023::get_solver(puzzle.day, params),\n        2024 =>"));
+
+    assert!(read(&root, "tests/examples/main.rs").ends_with("mod y2023;\nmod y2024;\n"));
+    let tests = read(&root, "tests/examples/y2023.rs");
+    assert!(tests.contains("Puzzle::new(2023, day)"));
+    assert!(tests.contains("fn day5() {"));
+
+    // The next day of the year goes into the new module
+    scaffold::new_day(&root, Puzzle::new(2023, 6)).unwrap();
+    assert!(read(&root, "src/y2023/mod.rs").contains("pub mod day5;\npub mod day6;\n"));
+    assert!(read(&root, "tests/examples/y2023.rs").contains("fn day6() {"));
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn never_overwrites_anything() {
+    let root = crate_root("existing");
+    fs::write(root.join("src/examples/2024/example-day4.txt"), "mine").unwrap();
+
+    let error = scaffold::new_day(&root, Puzzle::new(2024, 4)).unwrap_err();
+    assert!(
+        matches!(error, ScaffoldError::Exists { ref path } if path.ends_with("example-day4.txt"))
+    );
+    let error = scaffold::new_day(&root, Puzzle::new(2024, 3)).unwrap_err();
+    assert!(matches!(
+        error,
+        ScaffoldError::Registered { puzzle, .. } if puzzle == Puzzle::new(2024, 3)
+    ));
+
+    // Nothing was written on the way
+    assert!(!root.join("src/y2024/day4.rs").exists());
+    assert!(!root.join("src/y2024/day3.rs").exists());
+    assert_eq!(read(&root, "src/y2024/mod.rs"), MODULE);
+    assert_eq!(read(&root, "tests/examples/y2024.rs"), TESTS);
+    assert_eq!(read(&root, "src/examples/2024/example-day4.txt"), "mine");
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,98 @@
+use std::path::Path;
+
+use aoc::geometry::Vec2;
+use aoc::grid::Grid;
+use aoc::input;
+use aoc::visualize::{get_visualizer, Color, Frame, Tile};
+use aoc::{Mode, Params, Puzzle};
+
+fn example_frames(day: u8) -> Vec<Frame> {
+    let params = Params::with_mode(Mode::Example);
+    let puzzle = Puzzle::new(2024, day);
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(input::example_path(puzzle));
+    let content = std::fs::read_to_string(path).unwrap();
+    let visualizer = get_visualizer(puzzle, &params).unwrap();
+    let input = visualizer.parse(&content).unwrap();
+    let frames = visualizer.frames(input.as_ref()).collect();
+    frames
+}
+
+fn count(frame: &Frame, symbol: char, color: Color) -> usize {
+    frame
+        .grid
+        .iter()
+        .filter(|(_, tile)| **tile == Tile::colored(symbol, color))
+        .count()
+}
+
+#[test]
+fn only_grid_days_are_visualized() {
+    let params = Params::with_mode(Mode::Example);
+    let days: Vec<u8> = aoc::DAYS
+        .filter(|&day| get_visualizer(Puzzle::new(2024, day), &params).is_some())
+        .collect();
+
+    assert_eq!(days, [6, 12, 14, 15, 16, 18, 20]);
+    assert!(get_visualizer(Puzzle::new(2023, 6), &params).is_none());
+}
+
+#[test]
+fn guard_walks_then_loop_obstructions_show() {
+    let frames = example_frames(6);
+    let first = &frames[0];
+    let last = frames.last().unwrap();
+
+    assert_eq!(count(first, '^', Color::Red), 1);
+    assert_eq!(count(last, 'O', Color::Magenta), 6);
+    assert_eq!(last.caption, "6 obstructions causing a loop");
+}
+
+#[test]
+fn one_frame_per_move_of_the_warehouse_robot() {
+    let frames = example_frames(15);
+
+    assert_eq!(frames.len(), 700 + 1);
+    assert!(frames.iter().all(|f| count(f, '@', Color::Red) == 1));
+    assert!(frames.last().unwrap().caption.ends_with("GPS sum 10092"));
+}
+
+#[test]
+fn paths_and_blocking_byte() {
+    let frames = example_frames(16);
+    assert_eq!(frames[1].caption, "45 tiles on a best path");
+    assert_eq!(count(&frames[1], 'O', Color::Green), 45 - 2);
+
+    let frames = example_frames(18);
+    let last = frames.last().unwrap();
+    assert_eq!(last.caption, "21 bytes fallen, 6,1 cuts off the exit");
+    assert_eq!(count(last, '#', Color::Red), 1);
+    assert_eq!(count(last, 'O', Color::Green), 0);
+}
+
+#[test]
+fn robots_stop_once_back_where_they_started() {
+    let params = Params {
+        width: Some(2),
+        height: Some(2),
+        ..Params::with_mode(Mode::Example)
+    };
+    let visualizer = get_visualizer(Puzzle::new(2024, 14), &params).unwrap();
+    // Both robots always share a tile, so there is never a Christmas tree
+    let input = visualizer.parse("p=0,0 v=1,1\np=0,0 v=1,1").unwrap();
+
+    assert_eq!(visualizer.frames(input.as_ref()).count(), 2 * 2 + 1);
+}
+
+#[test]
+fn renders_with_and_without_colors() {
+    let mut grid = Grid::new(3, 2, Tile::plain('.'));
+    grid[Vec2::new(0, 1)] = Tile::colored('#', Color::Gray);
+    grid[Vec2::new(1, 2)] = Tile::colored('@', Color::Red);
+    let frame = Frame::new("step 1", grid);
+
+    assert_eq!(frame.to_string(), "step 1\n.#.\n..@\n");
+    assert_eq!(
+        frame.to_ansi(),
+        "step 1\n.\x1b[90m#\x1b[0m.\n..\x1b[31m@\x1b[0m\n"
+    );
+}